@@ -0,0 +1,149 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::actions::LabelChange;
+use crate::config::AutoLabel;
+use crate::github::PrContext;
+use crate::rules::conventional::title_type;
+use crate::rules::{glob, has_label};
+use globset::GlobSet;
+use std::collections::BTreeMap;
+
+/// Adds the title type's label and `area/*` style labels for changed paths.
+/// Labels are only ever added, never removed.
+pub struct AutoLabeler {
+	title: bool,
+	type_labels: BTreeMap<String, Vec<String>>,
+	paths: Vec<(String, GlobSet)>,
+}
+
+impl AutoLabeler {
+	pub fn new(
+		config: &AutoLabel,
+		type_labels: BTreeMap<String, Vec<String>>,
+	) -> crate::error::Result<Self> {
+		let paths = config
+			.paths
+			.iter()
+			.flatten()
+			.map(|(label, globs)| Ok((label.clone(), glob::set(globs)?)))
+			.collect::<crate::error::Result<Vec<_>>>()?;
+
+		Ok(Self {
+			title: config.title,
+			type_labels,
+			paths,
+		})
+	}
+
+	pub async fn plan(&self, ctx: &PrContext<'_>) -> crate::error::Result<Vec<LabelChange>> {
+		let pr = &ctx.pr;
+		let mut labels: Vec<String> = Vec::new();
+
+		// The first label mapped to the title type, unless any of them is set
+		if self.title
+			&& let Some(kind) = title_type(&pr.title)
+			&& let Some(mapped) = self.type_labels.get(&kind)
+			&& !mapped.iter().any(|l| has_label(pr, l))
+			&& let Some(first) = mapped.first()
+		{
+			labels.push(first.clone());
+		}
+
+		if !self.paths.is_empty() {
+			let files = ctx.files().await?;
+			for (label, globs) in &self.paths {
+				if !has_label(pr, label)
+					&& !labels.contains(label)
+					&& files.iter().any(|f| globs.is_match(&f.filename))
+				{
+					labels.push(label.clone());
+				}
+			}
+		}
+
+		Ok(labels.into_iter().map(LabelChange::Add).collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::PullRequestLabel;
+	use crate::github::{PullRequest, PullRequestFile};
+
+	const CONFIG: &str = "title: true
+paths:
+  area/api: [\"api/**\"]
+  area/docs: [\"docs/**\", \"**/*.md\"]
+";
+
+	fn create_ctx(title: &str, labels: Vec<&str>, files: Vec<&str>) -> PrContext<'static> {
+		let pr = PullRequest {
+			title: title.to_string(),
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		};
+		PrContext::new(pr).with_files(
+			files
+				.into_iter()
+				.map(|name| PullRequestFile {
+					filename: name.to_string(),
+					..Default::default()
+				})
+				.collect(),
+		)
+	}
+
+	fn create_labeler() -> AutoLabeler {
+		let type_labels = BTreeMap::from([(
+			"fix".to_string(),
+			vec!["kind/bug".to_string(), "bug".to_string()],
+		)]);
+		AutoLabeler::new(&serde_yaml::from_str(CONFIG).unwrap(), type_labels).unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_title_and_path_labels() {
+		let ctx = create_ctx("fix(api): crash", vec![], vec!["api/v1.rs", "README.md"]);
+		let changes = create_labeler().plan(&ctx).await.unwrap();
+		assert_eq!(
+			changes,
+			vec![
+				LabelChange::Add("kind/bug".to_string()),
+				LabelChange::Add("area/api".to_string()),
+				LabelChange::Add("area/docs".to_string()),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn test_existing_labels_are_kept() {
+		let ctx = create_ctx("fix: crash", vec!["bug", "area/api"], vec!["api/v1.rs"]);
+		let changes = create_labeler().plan(&ctx).await.unwrap();
+		assert!(changes.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_unknown_title_type() {
+		let ctx = create_ctx("Bump serde from 1.0 to 1.1", vec![], vec!["Cargo.toml"]);
+		let changes = create_labeler().plan(&ctx).await.unwrap();
+		assert!(changes.is_empty());
+	}
+}
@@ -0,0 +1,131 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mutations pr-checker performs on the PR itself, as opposed to checks.
+
+pub mod autolabel;
+pub mod size_labels;
+
+pub use autolabel::AutoLabeler;
+pub use size_labels::SizeLabeler;
+
+use crate::github::pr::PullRequestLabel;
+use crate::github::{GitHubClient, PullRequest};
+use std::fmt;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelChange {
+	Add(String),
+	Remove(String),
+}
+
+impl fmt::Display for LabelChange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			LabelChange::Add(label) => write!(f, "add label '{}'", label),
+			LabelChange::Remove(label) => write!(f, "remove label '{}'", label),
+		}
+	}
+}
+
+/// Apply label changes through the API and mirror them on `pr.labels` so
+/// rules evaluated afterwards see the updated set. Failed writes, e.g. with
+/// the read-only token of a fork PR, are logged and left out of the returned
+/// changes rather than failing the run.
+pub async fn apply(
+	client: &GitHubClient,
+	pr: &mut PullRequest,
+	changes: &[LabelChange],
+) -> Vec<LabelChange> {
+	let mut applied = Vec::new();
+
+	for change in changes {
+		if let LabelChange::Remove(label) = change {
+			match client.remove_label(pr.number, label).await {
+				Ok(()) => {
+					pr.labels.retain(|l| &l.name != label);
+					info!("Removed label '{}'", label);
+					applied.push(change.clone());
+				}
+				Err(e) => warn!("Could not remove label '{}': {}", label, e),
+			}
+		}
+	}
+
+	let added: Vec<String> = changes
+		.iter()
+		.filter_map(|c| match c {
+			LabelChange::Add(label) => Some(label.clone()),
+			LabelChange::Remove(_) => None,
+		})
+		.collect();
+	if !added.is_empty() {
+		match client.add_labels(pr.number, &added).await {
+			Ok(()) => {
+				for label in added {
+					info!("Added label '{}'", label);
+					pr.labels.push(PullRequestLabel {
+						name: label.clone(),
+					});
+					applied.push(LabelChange::Add(label));
+				}
+			}
+			Err(e) => warn!("Could not add labels {}: {}", added.join(", "), e),
+		}
+	}
+
+	applied
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use mockito::Server;
+
+	#[tokio::test]
+	async fn test_failed_writes_are_skipped() {
+		let mut server = Server::new_async().await;
+		server
+			.mock("POST", "/repos/owner/repo/issues/1/labels")
+			.with_status(403)
+			.with_body(r#"{"message": "Resource not accessible by integration"}"#)
+			.create_async()
+			.await;
+		server
+			.mock("DELETE", "/repos/owner/repo/issues/1/labels/stale")
+			.with_body("[]")
+			.create_async()
+			.await;
+
+		let client =
+			GitHubClient::new("t0ken".to_string(), "owner".to_string(), "repo".to_string())
+				.with_api_url(server.url());
+		let mut pr = PullRequest {
+			number: 1,
+			labels: vec![PullRequestLabel {
+				name: "stale".to_string(),
+			}],
+			..Default::default()
+		};
+		let changes = [
+			LabelChange::Add("size/XS".to_string()),
+			LabelChange::Remove("stale".to_string()),
+		];
+
+		let applied = apply(&client, &mut pr, &changes).await;
+		assert_eq!(applied, vec![LabelChange::Remove("stale".to_string())]);
+		assert!(pr.labels.is_empty());
+	}
+}
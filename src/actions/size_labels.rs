@@ -0,0 +1,145 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::actions::LabelChange;
+use crate::config::SizeLabels;
+use crate::github::PrContext;
+use crate::rules::size::measure;
+use crate::rules::{glob, has_label};
+use globset::GlobSet;
+use tracing::info;
+
+/// Keeps exactly one size label from the configured buckets on the PR.
+pub struct SizeLabeler {
+	config: SizeLabels,
+	exclude: GlobSet,
+}
+
+impl SizeLabeler {
+	pub fn new(config: &SizeLabels) -> crate::error::Result<Self> {
+		if config.buckets.is_empty() {
+			return Err(crate::error::Error::Config(
+				"`size_labels.buckets` must not be empty".to_string(),
+			));
+		}
+		Ok(Self {
+			exclude: glob::set(config.exclude.as_deref().unwrap_or_default())?,
+			config: config.clone(),
+		})
+	}
+
+	pub async fn plan(&self, ctx: &PrContext<'_>) -> crate::error::Result<Vec<LabelChange>> {
+		let stats = measure(ctx, &self.exclude).await?;
+		let lines = stats.lines();
+
+		// First bucket whose limit fits; a bucket without `max_lines` takes the rest
+		let wanted = self
+			.config
+			.buckets
+			.iter()
+			.find(|b| b.max_lines.is_none_or(|max| lines <= max))
+			.map(|b| b.label.as_str());
+		info!(
+			"Size label for {}: {}",
+			stats.explain(),
+			wanted.unwrap_or("none")
+		);
+
+		let mut changes: Vec<LabelChange> = self
+			.config
+			.buckets
+			.iter()
+			.map(|b| b.label.as_str())
+			.filter(|&label| Some(label) != wanted && has_label(&ctx.pr, label))
+			.map(|label| LabelChange::Remove(label.to_string()))
+			.collect();
+
+		if let Some(label) = wanted
+			&& !has_label(&ctx.pr, label)
+		{
+			changes.push(LabelChange::Add(label.to_string()));
+		}
+
+		Ok(changes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::PullRequest;
+	use crate::github::pr::PullRequestLabel;
+
+	const CONFIG: &str = "buckets:
+  - label: size/XS
+    max_lines: 9
+  - label: size/S
+    max_lines: 49
+  - label: size/L
+";
+
+	fn create_ctx(lines: u64, labels: Vec<&str>) -> PrContext<'static> {
+		PrContext::new(PullRequest {
+			additions: lines,
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		})
+	}
+
+	fn create_labeler() -> SizeLabeler {
+		SizeLabeler::new(&serde_yaml::from_str(CONFIG).unwrap()).unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_adds_matching_label() {
+		let changes = create_labeler()
+			.plan(&create_ctx(20, vec![]))
+			.await
+			.unwrap();
+		assert_eq!(changes, vec![LabelChange::Add("size/S".to_string())]);
+	}
+
+	#[tokio::test]
+	async fn test_replaces_stale_label() {
+		let ctx = create_ctx(500, vec!["size/XS", "kind/bug"]);
+		let changes = create_labeler().plan(&ctx).await.unwrap();
+		assert_eq!(
+			changes,
+			vec![
+				LabelChange::Remove("size/XS".to_string()),
+				LabelChange::Add("size/L".to_string()),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn test_up_to_date() {
+		let changes = create_labeler()
+			.plan(&create_ctx(9, vec!["size/XS"]))
+			.await
+			.unwrap();
+		assert!(changes.is_empty());
+	}
+
+	#[test]
+	fn test_empty_buckets() {
+		let config: SizeLabels = serde_yaml::from_str("buckets: []\n").unwrap();
+		assert!(SizeLabeler::new(&config).is_err());
+	}
+}
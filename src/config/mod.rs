@@ -14,4 +14,9 @@
 
 pub mod schema;
 
-pub use schema::{Config, LabelRule, TitleRule};
+pub use schema::{
+	AlignmentRule, ApiConfig, AutoLabel, BodyRule, BranchRule, CommitRule, Config,
+	ConventionalOptions, DcoRule, Exemptions, FileRule, LabelGroup, LabelRule, Mode, ReferenceRule,
+	ReferenceSource, RuleSection, RuleSeverity, SizeLabels, SizeRule, StateAction, StateRule,
+	SubjectCase, TemplateRule, TitleRule, When,
+};
@@ -13,30 +13,532 @@
 // limitations under the License.
 
 use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
+	/// `enforce` fails the job on errors, `advisory` only annotates
+	#[serde(default)]
+	pub mode: Mode,
+	/// Rule ids to evaluate, in order. When unset every configured rule runs
+	/// in the default order; rules not listed here are disabled.
+	pub rules: Option<Vec<String>>,
+	pub state: Option<StateRule>,
 	pub title: Option<TitleRule>,
 	pub labels: Option<LabelRule>,
+	pub body: Option<BodyRule>,
+	pub template: Option<TemplateRule>,
+	pub files: Option<FileRule>,
+	pub size: Option<SizeRule>,
+	pub branch: Option<BranchRule>,
+	pub references: Option<ReferenceRule>,
+	pub commits: Option<CommitRule>,
+	pub dco: Option<DcoRule>,
+	/// Apply a `size/*` label matching the PR's changed line count
+	pub size_labels: Option<SizeLabels>,
+	/// Apply labels derived from the title type and changed paths
+	pub autolabel: Option<AutoLabel>,
+	pub alignment: Option<AlignmentRule>,
+	/// PRs that skip every rule
+	pub exemptions: Option<Exemptions>,
+	/// Title type to label mapping, e.g. `fix: [kind/bug, bug]`
+	pub type_labels: Option<BTreeMap<String, LabelSet>>,
+	/// GitHub API client settings
+	pub api: Option<ApiConfig>,
 }
 
+/// A single label or a list of labels, any of which satisfies the mapping.
 #[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum LabelSet {
+	One(String),
+	Many(Vec<String>),
+}
+
+impl LabelSet {
+	pub fn to_vec(&self) -> Vec<String> {
+		match self {
+			LabelSet::One(label) => vec![label.clone()],
+			LabelSet::Many(labels) => labels.clone(),
+		}
+	}
+}
+
+/// Built-in mapping used when `type_labels` is not configured.
+const DEFAULT_TYPE_LABELS: &[(&str, &str)] = &[
+	("feat", "kind/feature"),
+	("fix", "kind/bug"),
+	("docs", "kind/docs"),
+	("chore", "kind/chore"),
+	("refactor", "kind/refactor"),
+	("test", "kind/test"),
+	("perf", "kind/performance"),
+	("ci", "kind/ci"),
+	("build", "kind/build"),
+	("security", "kind/security"),
+	("dependencies", "kind/dependencies"),
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+	#[default]
+	Enforce,
+	Advisory,
+}
+
+/// How violations of a config section are reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+	#[default]
+	Error,
+	Warning,
+	Off,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct TitleRule {
+	/// Severity of title violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
 	/// Regex pattern to match against PR title
 	pub pattern: Option<String>,
 	/// Minimum length of the title
 	pub min_length: Option<usize>,
 	/// Maximum length of the title
 	pub max_length: Option<usize>,
+	/// Conventional Commits checks, enabled when any option is set
+	#[serde(flatten)]
+	pub conventional: ConventionalOptions,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConventionalOptions {
+	/// Accepted types, e.g. `[feat, fix]`, compared case-insensitively
+	pub allowed_types: Option<Vec<String>>,
+	/// Accepted scopes, e.g. `[api, cli]`, compared case-insensitively
+	pub allowed_scopes: Option<Vec<String>>,
+	/// Require a `(scope)` after the type
+	#[serde(default)]
+	pub require_scope: bool,
+	/// Label that must be present when the `!` breaking marker is used
+	pub disallow_breaking_without_label: Option<String>,
+	/// Case of the first letter of the description
+	pub subject_case: Option<SubjectCase>,
+	/// Reject descriptions ending with `.`
+	#[serde(default)]
+	pub no_trailing_period: bool,
+}
+
+impl ConventionalOptions {
+	pub fn is_enabled(&self) -> bool {
+		self.allowed_types.is_some()
+			|| self.allowed_scopes.is_some()
+			|| self.require_scope
+			|| self.disallow_breaking_without_label.is_some()
+			|| self.subject_case.is_some()
+			|| self.no_trailing_period
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubjectCase {
+	/// `feat: add thing`
+	Lower,
+	/// `feat: Add thing`
+	Sentence,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct LabelRule {
+	/// Severity of label violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
 	/// List of required labels
 	pub required: Option<Vec<String>>,
+	/// Exactly one of these labels must be present
+	pub one_of: Option<Vec<String>>,
+	/// At least one of these labels must be present
+	pub at_least_one_of: Option<Vec<String>>,
+	/// No more than one of these labels may be present
+	pub at_most_one_of: Option<Vec<String>>,
+	/// None of these labels may be present, e.g. `do-not-merge`
+	pub forbidden: Option<Vec<String>>,
+	/// Count constraints on labels selected by prefix or regex
+	pub groups: Option<Vec<LabelGroup>>,
+}
+
+/// Labels selected by `prefix` or `pattern` (set exactly one), whose count
+/// must fall within `min` and `max`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LabelGroup {
+	pub prefix: Option<String>,
+	pub pattern: Option<String>,
+	pub min: Option<usize>,
+	pub max: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BodyRule {
+	/// Severity of description violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Minimum length of the description, ignoring surrounding whitespace
+	pub min_length: Option<usize>,
+	/// Maximum length of the description
+	pub max_length: Option<usize>,
+	/// Regex patterns that must all match somewhere in the description
+	pub required_patterns: Option<Vec<String>>,
+	/// Headings that must be present, e.g. `## Test plan`
+	pub required_headings: Option<Vec<String>>,
+	/// Text that must not appear, e.g. leftover template placeholders
+	pub forbidden: Option<Vec<String>>,
+	/// Require every `- [ ]` task list item to be ticked
+	#[serde(default)]
+	pub require_checked_boxes: bool,
+}
+
+/// PR description conformance to the repository's pull request template.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TemplateRule {
+	/// Severity of template violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Template to check against instead of the locations GitHub searches
+	pub path: Option<String>,
+}
+
+/// Rules over the files changed by the PR. Paths are globs where `*` stays
+/// within a directory and `**` spans directories.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FileRule {
+	/// Severity of changed-file violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Paths that must not be added or modified, e.g. `**/*.pem`
+	pub forbidden: Option<Vec<String>>,
+	/// Paths that must change together with others
+	pub require_together: Option<Vec<RequireTogether>>,
+	/// Maximum number of changed files
+	pub max_files: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequireTogether {
+	/// Touching a path matching this glob...
+	pub changed: String,
+	/// ...requires touching a path matching this one too
+	pub requires: String,
+}
+
+/// PR size thresholds, measured as reported by the pulls API or, with
+/// `exclude`, summed over the changed files that do not match.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SizeRule {
+	/// Severity of size violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Maximum added lines
+	pub max_additions: Option<u64>,
+	/// Maximum deleted lines
+	pub max_deletions: Option<u64>,
+	/// Maximum added plus deleted lines
+	pub max_lines: Option<u64>,
+	/// Maximum changed files
+	pub max_files: Option<u64>,
+	/// Globs left out of the size, e.g. lockfiles or vendored code
+	pub exclude: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BranchRule {
+	/// Severity of branch violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Regex pattern the head branch name must match
+	pub pattern: Option<String>,
+	/// Globs the base branch must match, e.g. `[main, "release/*"]`
+	pub allowed_bases: Option<Vec<String>>,
+	/// Require a `type/` head branch prefix to equal the title type
+	#[serde(default)]
+	pub match_title_type: bool,
 }
 
+/// Requires the PR to reference a tracker ticket.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReferenceRule {
+	/// Severity of reference violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Regex patterns for ticket ids (default: `JIRA-123` and `#123` styles)
+	pub patterns: Option<Vec<String>>,
+	/// Where to look for references (default: title, body and branch)
+	pub sources: Option<Vec<ReferenceSource>>,
+	/// Check that referenced `#123` issues exist and are open
+	#[serde(default)]
+	pub verify_issues: bool,
+	/// Labels that waive the requirement, e.g. `kind/chore`
+	pub exempt_labels: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferenceSource {
+	Title,
+	Body,
+	Branch,
+}
+
+/// Lints the message of every commit in the PR.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommitRule {
+	/// Severity of commit violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Conventional Commits checks for each subject; `{}` only checks the format
+	pub conventional: Option<ConventionalOptions>,
+	/// Maximum length of the subject line
+	pub max_subject_length: Option<usize>,
+	/// Require an empty line between the subject and the body
+	#[serde(default)]
+	pub require_blank_line: bool,
+	/// Maximum length of body lines; lines without spaces (URLs) are ignored
+	pub max_body_line_length: Option<usize>,
+	/// Reject `fixup!`, `squash!` and `amend!` commits
+	#[serde(default)]
+	pub forbid_autosquash: bool,
+	/// Reject subjects starting with `WIP`
+	#[serde(default)]
+	pub forbid_wip: bool,
+	/// Maximum number of commits in the PR
+	pub max_commits: Option<usize>,
+	/// Require every commit to carry a signature GitHub verified
+	#[serde(default)]
+	pub require_signed: bool,
+	/// Let unsigned commits merging the base branch into the PR pass
+	/// `require_signed`
+	#[serde(default)]
+	pub allow_unsigned_base_merges: bool,
+}
+
+/// Developer Certificate of Origin: every commit must be signed off by its
+/// author.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DcoRule {
+	/// Severity of sign-off violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// Accept sign-offs with `@users.noreply.github.com` addresses
+	#[serde(default)]
+	pub allow_noreply: bool,
+	/// Skip commits authored by bot accounts
+	#[serde(default)]
+	pub allow_bots: bool,
+	/// Require `Co-authored-by:` trailers to read `Name <email>`
+	#[serde(default)]
+	pub check_co_authors: bool,
+}
+
+/// GitHub API client settings: retries for transient failures (5xx
+/// responses, rate limits and network errors) and list size limits.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ApiConfig {
+	/// Retries per request (default: 3, `0` disables retrying)
+	pub max_retries: Option<u32>,
+	/// Delay before the first retry in milliseconds, doubled for each
+	/// further attempt (default: 1000)
+	pub initial_backoff_ms: Option<u64>,
+	/// Longest delay in seconds; rate limits resetting later fail instead
+	/// (default: 60)
+	pub max_backoff_secs: Option<u64>,
+	/// Items fetched per list, e.g. changed files; longer lists are checked
+	/// partially with a warning (default: 3000)
+	pub max_items: Option<usize>,
+	/// Load the PR, its files and commits with one GraphQL query, falling
+	/// back to REST when it fails
+	#[serde(default)]
+	pub graphql: bool,
+}
+
+/// Gates on the PR's readiness: draft state, WIP titles and blocking labels.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StateRule {
+	/// Severity of state violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+	/// What to do with draft PRs
+	pub draft: Option<StateAction>,
+	/// What to do when the title contains `WIP`, `[WIP]` or `DO NOT MERGE`
+	pub wip: Option<StateAction>,
+	/// Labels that mark a PR as not ready, e.g. `do-not-merge`
+	pub blocking_labels: Option<Vec<String>>,
+	/// What to do when a blocking label is present (default: fail)
+	pub on_blocking_label: Option<StateAction>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateAction {
+	/// Report a violation
+	#[default]
+	Fail,
+	/// Skip every other rule
+	Skip,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SizeLabels {
+	/// Only label when the PR matches these predicates
+	pub when: Option<When>,
+	/// Buckets in ascending order; the first one that fits is applied
+	pub buckets: Vec<SizeBucket>,
+	/// Globs left out of the line count, as in `size.exclude`
+	pub exclude: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SizeBucket {
+	pub label: String,
+	/// Largest added plus deleted line count for this bucket; omit on the
+	/// last bucket to catch everything larger
+	pub max_lines: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AutoLabel {
+	/// Only label when the PR matches these predicates
+	pub when: Option<When>,
+	/// Add the first `type_labels` label for the title type
+	#[serde(default)]
+	pub title: bool,
+	/// Label to add when any changed file matches one of its globs
+	pub paths: Option<BTreeMap<String, Vec<String>>>,
+}
+
+/// PRs matching any of these skip all rules; labels are still applied.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Exemptions {
+	/// Author logins, e.g. `renovate[bot]`
+	pub authors: Option<Vec<String>>,
+	/// Author associations, e.g. `OWNER`, `MEMBER`, `FIRST_TIME_CONTRIBUTOR`
+	pub associations: Option<Vec<String>>,
+	/// Exempt every bot account
+	#[serde(default)]
+	pub bots: bool,
+	/// Labels that bypass the checks
+	pub labels: Option<Vec<String>>,
+}
+
+/// Predicates deciding whether a section applies to a PR. Every predicate
+/// that is set must hold; within a list, any entry may match. Branches and
+/// paths are globs.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct When {
+	/// Author login is one of these
+	pub authors: Option<Vec<String>>,
+	/// Author login is none of these, e.g. `dependabot[bot]`
+	pub exclude_authors: Option<Vec<String>>,
+	/// Target branch matches one of these
+	pub base_branches: Option<Vec<String>>,
+	/// Source branch matches one of these
+	pub head_branches: Option<Vec<String>>,
+	/// PR has at least one of these labels
+	pub labels: Option<Vec<String>>,
+	/// PR has none of these labels
+	pub exclude_labels: Option<Vec<String>>,
+	/// PR draft state
+	pub draft: Option<bool>,
+	/// At least one changed file matches one of these
+	pub paths: Option<Vec<String>>,
+}
+
+/// Title type to `kind/*` label alignment, checked against `labels.required`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AlignmentRule {
+	/// Severity of alignment violations (default: error)
+	pub severity: Option<RuleSeverity>,
+	/// Only run when the PR matches these predicates
+	pub when: Option<When>,
+}
+
+/// Settings shared by every rule section.
+pub trait RuleSection {
+	fn severity(&self) -> Option<RuleSeverity>;
+	fn when(&self) -> Option<&When>;
+}
+
+macro_rules! rule_sections {
+	($($section:ty),* $(,)?) => {$(
+		impl RuleSection for $section {
+			fn severity(&self) -> Option<RuleSeverity> {
+				self.severity
+			}
+
+			fn when(&self) -> Option<&When> {
+				self.when.as_ref()
+			}
+		}
+	)*};
+}
+
+rule_sections!(
+	StateRule,
+	TitleRule,
+	LabelRule,
+	AlignmentRule,
+	BodyRule,
+	TemplateRule,
+	FileRule,
+	SizeRule,
+	BranchRule,
+	ReferenceRule,
+	CommitRule,
+	DcoRule,
+);
+
 impl Config {
+	/// Title type to labels mapping, falling back to the built-in table.
+	/// Types are lowercased so lookups can use the normalized title type.
+	pub fn type_labels(&self) -> BTreeMap<String, Vec<String>> {
+		match &self.type_labels {
+			Some(map) => map
+				.iter()
+				.map(|(kind, labels)| (kind.to_lowercase(), labels.to_vec()))
+				.collect(),
+			None => DEFAULT_TYPE_LABELS
+				.iter()
+				.map(|(kind, label)| (kind.to_string(), vec![label.to_string()]))
+				.collect(),
+		}
+	}
+
+	/// Lowercased title types the config knows about: `type_labels` keys
+	/// plus the title's `allowed_types`.
+	pub fn known_types(&self) -> BTreeSet<String> {
+		let mut types: BTreeSet<String> = self.type_labels().into_keys().collect();
+		if let Some(allowed) = self
+			.title
+			.as_ref()
+			.and_then(|t| t.conventional.allowed_types.as_ref())
+		{
+			types.extend(allowed.iter().map(|t| t.to_lowercase()));
+		}
+		types
+	}
+
 	pub fn from_file(path: &str) -> crate::error::Result<Self> {
 		let content = std::fs::read_to_string(path)?;
 		let config: Config = serde_yaml::from_str(&content)?;
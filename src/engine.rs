@@ -12,115 +12,194 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::config::Config;
-use crate::github::GitHubClient;
-use crate::rules::Violation;
-use crate::rules::{RuleResult, check_labels, check_title};
+use crate::actions::{self, AutoLabeler, LabelChange, SizeLabeler};
+use crate::config::{Config, When};
+use crate::github::{GitHubClient, PrContext};
+use crate::rules::condition::Condition;
+use crate::rules::exemption::exemption_reason;
+use crate::rules::registry::RegisteredRule;
+use crate::rules::{RuleRegistry, RuleResult, Violation, format_labels};
+use tracing::{debug, info, warn};
+
+/// Outcome of a run: rule violations plus label changes made to the PR.
+pub struct Report {
+	pub violations: RuleResult,
+	/// Changes applied, or only planned when running with `dry_run`
+	pub label_changes: Vec<LabelChange>,
+}
 
 pub struct Engine {
 	client: GitHubClient,
 	config: Config,
+	dry_run: bool,
 }
 
 impl Engine {
 	pub fn new(client: GitHubClient, config: Config) -> Self {
-		Self { client, config }
+		Self {
+			client,
+			config,
+			dry_run: false,
+		}
 	}
 
-	pub async fn run(&self, pr_number: u64) -> crate::error::Result<RuleResult> {
-		let pr = self.client.get_pr(pr_number).await?;
+	/// Log intended PR mutations instead of performing them.
+	pub fn dry_run(mut self, dry_run: bool) -> Self {
+		self.dry_run = dry_run;
+		self
+	}
+
+	pub async fn run(&self, pr_number: u64) -> crate::error::Result<Report> {
+		let registry = RuleRegistry::from_config(&self.config)?;
+		let mut ctx = self.load_context(pr_number).await?;
 
 		let mut all_violations = Vec::new();
 
-		// Check title rule
-		if let Some(title_rule) = &self.config.title {
-			let violations = check_title(&pr, title_rule);
-			all_violations.extend(violations);
+		if let Some(exemptions) = &self.config.exemptions
+			&& let Some(reason) = exemption_reason(exemptions, &ctx.pr)
+		{
+			info!("Skipping all rules for PR #{}: {}", ctx.pr.number, reason);
+			return Ok(Report {
+				violations: all_violations,
+				label_changes: Vec::new(),
+			});
 		}
 
-		// Check labels rule
-		if let Some(labels_rule) = &self.config.labels {
-			let violations = check_labels(&pr, labels_rule);
-			all_violations.extend(violations);
+		// `state` is always registered first and may skip the whole run,
+		// labelling included, so it is evaluated before labels change
+		let rules = registry.rules();
+		let gates = rules.iter().take_while(|r| r.rule.id() == "state").count();
+		for entry in &rules[..gates] {
+			if let Some(violations) = self.evaluate(entry, &ctx).await? {
+				all_violations.extend(violations);
+			}
+			if let Some(reason) = ctx.skip_reason() {
+				info!("Skipping all rules for PR #{}: {}", ctx.pr.number, reason);
+				return Ok(Report {
+					violations: Vec::new(),
+					label_changes: Vec::new(),
+				});
+			}
 		}
 
-		// Ensure title type aligns with kind/* label
-		// Only check if required labels are configured and non-empty
-		if let Some(labels_rule) = &self.config.labels
-			&& let Some(required) = &labels_rule.required
-			&& !required.is_empty()
-		{
-			// Check if title type's expected label is in required list
-			if let Some(expected) = expected_label_for_title(&pr.title)
-				&& required.contains(&expected.to_string())
-				&& !has_label(&pr, expected)
-			{
-				all_violations.push(Violation {
-					message: format!(
-						"Title type '{}' requires label '{}', current labels: [{}], title: '{}'",
-						title_type(&pr.title),
-						expected,
-						format_labels(&pr),
-						pr.title
-					),
-				});
+		let mut changes = self.plan_label_changes(&ctx).await?;
+		if self.dry_run {
+			for change in &changes {
+				info!("[dry-run] Would {}", change);
 			}
+		} else {
+			changes = actions::apply(&self.client, &mut ctx.pr, &changes).await;
+		}
+
+		for entry in &rules[gates..] {
+			if let Some(violations) = self.evaluate(entry, &ctx).await? {
+				all_violations.extend(violations);
+			}
+		}
+
+		// Rules only saw part of a list, cut off by `api.max_items` or by
+		// GitHub's own listing limits
+		for what in ctx.truncated() {
+			all_violations.push(Violation::warning(
+				"api/truncated",
+				format!(
+					"Only the first {} were checked; raising `api.max_items` helps unless GitHub's listing limit was hit",
+					what
+				),
+			));
 		}
 
 		// Prepend a context line with title and labels if there are violations
 		if !all_violations.is_empty() {
 			all_violations.insert(
 				0,
-				Violation {
-					message: format!(
+				Violation::notice(
+					"context",
+					format!(
 						"Context -> title: '{}'; labels: [{}]",
-						pr.title,
-						format_labels(&pr)
+						ctx.pr.title,
+						format_labels(&ctx.pr)
 					),
-				},
+				),
 			);
 		}
 
-		Ok(all_violations)
+		Ok(Report {
+			violations: all_violations,
+			label_changes: changes,
+		})
 	}
-}
 
-fn has_label(pr: &crate::github::PullRequest, name: &str) -> bool {
-	pr.labels.iter().any(|l| l.name == name)
-}
+	/// Evaluate one rule, or `None` when its `when:` does not match.
+	async fn evaluate(
+		&self,
+		entry: &RegisteredRule,
+		ctx: &PrContext<'_>,
+	) -> crate::error::Result<Option<RuleResult>> {
+		let rule = &entry.rule;
+		if let Some(condition) = &entry.condition
+			&& !condition.matches(ctx).await?
+		{
+			debug!("Skipping rule '{}': `when` does not match", rule.id());
+			return Ok(None);
+		}
+		debug!("Evaluating rule '{}': {}", rule.id(), rule.description());
+		let violations = rule.evaluate(ctx).await?;
+		Ok(Some(entry.apply_severity(violations)))
+	}
 
-fn format_labels(pr: &crate::github::PullRequest) -> String {
-	if pr.labels.is_empty() {
-		"none".to_string()
-	} else {
-		pr.labels
-			.iter()
-			.map(|l| l.name.clone())
-			.collect::<Vec<_>>()
-			.join(", ")
+	/// Fetch the PR, with a single GraphQL query when `api.graphql` is set.
+	async fn load_context(&self, pr_number: u64) -> crate::error::Result<PrContext<'_>> {
+		if self.config.api.as_ref().is_some_and(|api| api.graphql) {
+			match self.client.load_pr_graphql(pr_number).await {
+				Ok(snapshot) => {
+					let mut ctx = PrContext::new(snapshot.pr).with_client(&self.client);
+					if let Some(files) = snapshot.files {
+						ctx = ctx.with_files(files);
+					}
+					if let Some(commits) = snapshot.commits {
+						ctx = ctx.with_commits(commits);
+					}
+					if let Some(reviews) = snapshot.reviews {
+						ctx = ctx.with_reviews(reviews);
+					}
+					return Ok(ctx);
+				}
+				Err(e) => warn!("GraphQL query failed, falling back to REST: {}", e),
+			}
+		}
+		let pr = self.client.get_pr(pr_number).await?;
+		Ok(PrContext::new(pr).with_client(&self.client))
 	}
-}
 
-fn title_type(title: &str) -> String {
-	let prefix = title.split(':').next().unwrap_or_default().trim();
-	// Support optional component scope, e.g., feat(api-server): ...
-	let type_only = prefix.split('(').next().unwrap_or(prefix).trim();
-	type_only.to_lowercase()
+	async fn plan_label_changes(
+		&self,
+		ctx: &PrContext<'_>,
+	) -> crate::error::Result<Vec<LabelChange>> {
+		let mut changes = Vec::new();
+		if let Some(size_labels) = &self.config.size_labels
+			&& applies(size_labels.when.as_ref(), ctx).await?
+		{
+			changes.extend(SizeLabeler::new(size_labels)?.plan(ctx).await?);
+		}
+		if let Some(autolabel) = &self.config.autolabel
+			&& applies(autolabel.when.as_ref(), ctx).await?
+		{
+			let labeler = AutoLabeler::new(autolabel, self.config.type_labels())?;
+			for change in labeler.plan(ctx).await? {
+				if !changes.contains(&change) {
+					changes.push(change);
+				}
+			}
+		}
+		Ok(changes)
+	}
 }
 
-fn expected_label_for_title(title: &str) -> Option<&'static str> {
-	match title_type(title).as_str() {
-		"feat" => Some("kind/feature"),
-		"fix" => Some("kind/bug"),
-		"docs" => Some("kind/docs"),
-		"chore" => Some("kind/chore"),
-		"refactor" => Some("kind/refactor"),
-		"test" => Some("kind/test"),
-		"perf" => Some("kind/performance"),
-		"ci" => Some("kind/ci"),
-		"build" => Some("kind/build"),
-		"security" => Some("kind/security"),
-		"dependencies" => Some("kind/dependencies"),
-		_ => None,
+/// Whether an optional `when:` block matches the PR.
+async fn applies(when: Option<&When>, ctx: &PrContext<'_>) -> crate::error::Result<bool> {
+	match when {
+		Some(when) => Condition::new(when)?.matches(ctx).await,
+		None => Ok(true),
 	}
 }
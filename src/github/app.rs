@@ -0,0 +1,360 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::github::GitHubClient;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::{Mutex, OnceCell};
+use tracing::{debug, info};
+
+/// GitHub accepts app JWTs valid for at most ten minutes.
+const JWT_LIFETIME: Duration = Duration::from_secs(9 * 60);
+
+/// Backdate `iat` to tolerate clock drift between the runner and GitHub.
+const CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+/// Installation tokens are refreshed this long before they expire.
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// GitHub App credentials, exchanged for installation tokens on demand.
+pub struct AppAuth {
+	app_id: String,
+	key: EncodingKey,
+	installation_id: OnceCell<u64>,
+	token: Mutex<Option<InstallationToken>>,
+}
+
+struct InstallationToken {
+	token: String,
+	expires_at: SystemTime,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+	iat: u64,
+	exp: u64,
+	iss: &'a str,
+}
+
+#[derive(Deserialize)]
+struct Installation {
+	id: u64,
+}
+
+#[derive(Deserialize)]
+struct AccessToken {
+	token: String,
+	/// e.g. `2016-07-11T22:14:10Z`
+	expires_at: String,
+}
+
+impl AppAuth {
+	pub fn new(app_id: String, private_key_pem: &[u8]) -> crate::error::Result<Self> {
+		let key = EncodingKey::from_rsa_pem(private_key_pem).map_err(|e| {
+			crate::error::Error::Config(format!("Invalid GitHub App private key: {}", e))
+		})?;
+		Ok(Self {
+			app_id,
+			key,
+			installation_id: OnceCell::new(),
+			token: Mutex::new(None),
+		})
+	}
+
+	/// Skip looking up the installation of the target repository.
+	pub fn with_installation_id(self, installation_id: u64) -> Self {
+		let _ = self.installation_id.set(installation_id);
+		self
+	}
+
+	/// App credentials from `GITHUB_APP_ID` and `GITHUB_APP_PRIVATE_KEY` (the
+	/// PEM itself) or `GITHUB_APP_PRIVATE_KEY_PATH`, or `None` when no app
+	/// is configured.
+	pub fn from_env() -> crate::error::Result<Option<Self>> {
+		let Some(app_id) = env("GITHUB_APP_ID") else {
+			return Ok(None);
+		};
+
+		let pem = match (
+			env("GITHUB_APP_PRIVATE_KEY"),
+			env("GITHUB_APP_PRIVATE_KEY_PATH"),
+		) {
+			// Secrets pasted into a single line keep their newlines escaped
+			(Some(pem), _) => pem.replace("\\n", "\n").into_bytes(),
+			(None, Some(path)) => std::fs::read(&path)?,
+			(None, None) => {
+				return Err(crate::error::Error::Config(
+					"GITHUB_APP_ID is set but neither GITHUB_APP_PRIVATE_KEY nor GITHUB_APP_PRIVATE_KEY_PATH is"
+						.to_string(),
+				));
+			}
+		};
+		let app = Self::new(app_id, &pem)?;
+
+		match env("GITHUB_APP_INSTALLATION_ID") {
+			Some(id) => {
+				let id = id.parse().map_err(|_| {
+					crate::error::Error::Config(format!(
+						"GITHUB_APP_INSTALLATION_ID must be a number, got '{}'",
+						id
+					))
+				})?;
+				Ok(Some(app.with_installation_id(id)))
+			}
+			None => Ok(Some(app)),
+		}
+	}
+
+	pub fn app_id(&self) -> &str {
+		&self.app_id
+	}
+
+	/// A JWT authenticating as the app itself, signed with its private key.
+	fn jwt(&self, now: SystemTime) -> crate::error::Result<String> {
+		let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+		let claims = Claims {
+			iat: (now - CLOCK_SKEW.min(now)).as_secs(),
+			exp: (now + JWT_LIFETIME).as_secs(),
+			iss: &self.app_id,
+		};
+		jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.key).map_err(|e| {
+			crate::error::Error::Config(format!("Failed to sign GitHub App JWT: {}", e))
+		})
+	}
+
+	/// A valid installation token for the target repository, reusing the
+	/// cached one until it is about to expire.
+	pub async fn installation_token(&self, client: &GitHubClient) -> crate::error::Result<String> {
+		let mut cached = self.token.lock().await;
+		if let Some(token) = cached.as_ref()
+			&& token.expires_at > SystemTime::now() + REFRESH_MARGIN
+		{
+			return Ok(token.token.clone());
+		}
+
+		let jwt = self.jwt(SystemTime::now())?;
+		let installation_id = self
+			.installation_id
+			.get_or_try_init(|| self.find_installation(client, &jwt))
+			.await?;
+
+		let url = format!(
+			"{}/app/installations/{}/access_tokens",
+			client.api_url(),
+			installation_id
+		);
+		let response = client
+			.send_as(client.request(Method::POST, &url), &jwt)
+			.await?;
+		if !response.status().is_success() {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"Failed to create installation token for GitHub App {}: {}",
+				self.app_id,
+				response.status()
+			)));
+		}
+
+		let access: AccessToken = response.json().await?;
+		let expires_at = OffsetDateTime::parse(&access.expires_at, &Rfc3339).map_err(|e| {
+			crate::error::Error::GitHubApi(format!(
+				"Invalid installation token expiry '{}': {}",
+				access.expires_at, e
+			))
+		})?;
+		debug!("Installation token expires at {}", access.expires_at);
+
+		let token = access.token.clone();
+		*cached = Some(InstallationToken {
+			token: access.token,
+			expires_at: expires_at.into(),
+		});
+		Ok(token)
+	}
+
+	/// Id of the app installation covering the target repository.
+	async fn find_installation(
+		&self,
+		client: &GitHubClient,
+		jwt: &str,
+	) -> crate::error::Result<u64> {
+		let (owner, repo) = client.owner_and_repo();
+		let url = format!("{}/repos/{}/{}/installation", client.api_url(), owner, repo);
+		let response = client
+			.send_as(client.request(Method::GET, &url), jwt)
+			.await?;
+		if !response.status().is_success() {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"GitHub App {} is not installed on {}/{}: {}",
+				self.app_id,
+				owner,
+				repo,
+				response.status()
+			)));
+		}
+
+		let installation: Installation = response.json().await?;
+		info!(
+			"Using installation {} of GitHub App {}",
+			installation.id, self.app_id
+		);
+		Ok(installation.id)
+	}
+}
+
+fn env(name: &str) -> Option<String> {
+	std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::RetryPolicy;
+	use jsonwebtoken::{DecodingKey, Validation};
+	use mockito::{Matcher, Server};
+	use rsa::RsaPrivateKey;
+	use rsa::pkcs1::{EncodeRsaPrivateKey, LineEnding};
+	use rsa::rand_core::OsRng;
+	use std::sync::OnceLock;
+
+	/// A throwaway app key, generated once per test run.
+	fn key() -> &'static [u8] {
+		static KEY: OnceLock<String> = OnceLock::new();
+		KEY.get_or_init(|| {
+			RsaPrivateKey::new(&mut OsRng, 2048)
+				.unwrap()
+				.to_pkcs1_pem(LineEnding::LF)
+				.unwrap()
+				.to_string()
+		})
+		.as_bytes()
+	}
+
+	fn create_client(server: &Server, app: AppAuth) -> GitHubClient {
+		GitHubClient::from_app(app, "owner".to_string(), "repo".to_string())
+			.with_api_url(server.url())
+			.with_retry_policy(RetryPolicy {
+				max_retries: 0,
+				..Default::default()
+			})
+	}
+
+	fn expiry(from_now: Duration) -> String {
+		OffsetDateTime::from(SystemTime::now() + from_now)
+			.format(&Rfc3339)
+			.unwrap()
+	}
+
+	#[test]
+	fn test_jwt_claims() {
+		let app = AppAuth::new("12345".to_string(), key()).unwrap();
+		let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let jwt = app.jwt(now).unwrap();
+
+		let mut validation = Validation::new(Algorithm::RS256);
+		validation.insecure_disable_signature_validation();
+		validation.validate_exp = false;
+		let claims = jsonwebtoken::decode::<serde_json::Value>(
+			&jwt,
+			&DecodingKey::from_secret(&[]),
+			&validation,
+		)
+		.unwrap()
+		.claims;
+		assert_eq!(claims["iss"], "12345");
+		assert_eq!(claims["iat"], 1_700_000_000 - 60);
+		assert_eq!(claims["exp"], 1_700_000_000 + 9 * 60);
+	}
+
+	#[test]
+	fn test_invalid_key() {
+		assert!(AppAuth::new("1".to_string(), b"not a key").is_err());
+	}
+
+	#[tokio::test]
+	async fn test_installation_token_is_cached() {
+		let mut server = Server::new_async().await;
+		let lookup = server
+			.mock("GET", "/repos/owner/repo/installation")
+			.match_header("authorization", Matcher::Regex("^Bearer ey".to_string()))
+			.with_body(r#"{"id": 42}"#)
+			.create_async()
+			.await;
+		let exchange = server
+			.mock("POST", "/app/installations/42/access_tokens")
+			.with_status(201)
+			.with_body(format!(
+				r#"{{"token": "ghs_one", "expires_at": "{}"}}"#,
+				expiry(Duration::from_secs(3600))
+			))
+			.expect(1)
+			.create_async()
+			.await;
+		let issue = server
+			.mock("GET", "/repos/owner/repo/issues/1")
+			.match_header("authorization", "Bearer ghs_one")
+			.with_body(r#"{"state": "open"}"#)
+			.expect(2)
+			.create_async()
+			.await;
+
+		let app = AppAuth::new("12345".to_string(), key()).unwrap();
+		let client = create_client(&server, app);
+		client.get_issue(1).await.unwrap();
+		client.get_issue(1).await.unwrap();
+		lookup.assert_async().await;
+		exchange.assert_async().await;
+		issue.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_expiring_token_is_refreshed() {
+		let mut server = Server::new_async().await;
+		let exchange = server
+			.mock("POST", "/app/installations/7/access_tokens")
+			.with_status(201)
+			.with_body(format!(
+				r#"{{"token": "ghs_short", "expires_at": "{}"}}"#,
+				expiry(Duration::from_secs(60))
+			))
+			.expect(2)
+			.create_async()
+			.await;
+
+		let app = AppAuth::new("12345".to_string(), key())
+			.unwrap()
+			.with_installation_id(7);
+		let client = create_client(&server, app);
+		assert_eq!(client.token().await.unwrap(), "ghs_short");
+		assert_eq!(client.token().await.unwrap(), "ghs_short");
+		exchange.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_not_installed() {
+		let mut server = Server::new_async().await;
+		server
+			.mock("GET", "/repos/owner/repo/installation")
+			.with_status(404)
+			.create_async()
+			.await;
+
+		let app = AppAuth::new("12345".to_string(), key()).unwrap();
+		let error = create_client(&server, app).token().await.unwrap_err();
+		assert!(error.to_string().contains("not installed"));
+	}
+}
@@ -0,0 +1,199 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::github::pr::Listing;
+use crate::github::{
+	GitHubClient, PullRequest, PullRequestCommit, PullRequestFile, PullRequestReview,
+};
+use std::sync::{Mutex, OnceLock};
+use tokio::sync::OnceCell;
+
+/// Everything a rule may inspect about the pull request under check.
+///
+/// Data beyond the PR itself is fetched lazily on first use and cached, so
+/// rules only pay for the API calls they need.
+pub struct PrContext<'a> {
+	pub pr: PullRequest,
+	client: Option<&'a GitHubClient>,
+	files: OnceCell<Vec<PullRequestFile>>,
+	commits: OnceCell<Vec<PullRequestCommit>>,
+	reviews: OnceCell<Vec<PullRequestReview>>,
+	skip_reason: OnceLock<String>,
+	/// Lists that were only partially fetched, e.g. `250 of 400 commits`
+	truncated: Mutex<Vec<String>>,
+}
+
+impl<'a> PrContext<'a> {
+	pub fn new(pr: PullRequest) -> Self {
+		Self {
+			pr,
+			client: None,
+			files: OnceCell::new(),
+			commits: OnceCell::new(),
+			reviews: OnceCell::new(),
+			skip_reason: OnceLock::new(),
+			truncated: Mutex::new(Vec::new()),
+		}
+	}
+
+	pub fn with_client(mut self, client: &'a GitHubClient) -> Self {
+		self.client = Some(client);
+		self
+	}
+
+	/// Use already loaded files instead of fetching them.
+	pub fn with_files(self, files: Vec<PullRequestFile>) -> Self {
+		let _ = self.files.set(files);
+		self
+	}
+
+	/// Use already loaded commits instead of fetching them.
+	pub fn with_commits(self, commits: Vec<PullRequestCommit>) -> Self {
+		let _ = self.commits.set(commits);
+		self
+	}
+
+	/// Use already loaded reviews instead of fetching them.
+	pub fn with_reviews(self, reviews: Vec<PullRequestReview>) -> Self {
+		let _ = self.reviews.set(reviews);
+		self
+	}
+
+	pub fn client(&self) -> crate::error::Result<&'a GitHubClient> {
+		self.client.ok_or_else(|| {
+			crate::error::Error::GitHubApi("No GitHub client available for this PR".to_string())
+		})
+	}
+
+	/// Ask the engine to skip every other rule; the first reason given wins.
+	pub fn skip_all(&self, reason: impl Into<String>) {
+		let _ = self.skip_reason.set(reason.into());
+	}
+
+	pub fn skip_reason(&self) -> Option<&str> {
+		self.skip_reason.get().map(String::as_str)
+	}
+
+	/// Lists that were only partially fetched, e.g. `250 of 400 commits`.
+	pub fn truncated(&self) -> Vec<String> {
+		self.truncated
+			.lock()
+			.expect("truncation lock poisoned")
+			.clone()
+	}
+
+	/// Note a listing that was cut off, either at the client's `max_items`
+	/// or by GitHub itself, which stops listing PR files at 3000 and commits
+	/// at 250 whatever the PR's `total` says.
+	fn record<T>(&self, listing: Listing<T>, total: Option<u64>, what: &str) -> Vec<T> {
+		let fetched = listing.items.len() as u64;
+		let entry = match total {
+			Some(total) if fetched < total => Some(format!("{} of {} {}", fetched, total, what)),
+			_ if listing.truncated => Some(format!("{} {}", fetched, what)),
+			_ => None,
+		};
+		if let Some(entry) = entry {
+			self.truncated
+				.lock()
+				.expect("truncation lock poisoned")
+				.push(entry);
+		}
+		listing.items
+	}
+
+	/// Files changed by the PR.
+	pub async fn files(&self) -> crate::error::Result<&[PullRequestFile]> {
+		let files = self
+			.files
+			.get_or_try_init(|| async {
+				let listing = self.client()?.list_files(self.pr.number).await;
+				listing.map(|listing| {
+					self.record(listing, Some(self.pr.changed_files), "changed files")
+				})
+			})
+			.await?;
+		Ok(files)
+	}
+
+	/// Commits in the PR, oldest first.
+	pub async fn commits(&self) -> crate::error::Result<&[PullRequestCommit]> {
+		let commits = self
+			.commits
+			.get_or_try_init(|| async {
+				let listing = self.client()?.list_commits(self.pr.number).await;
+				listing.map(|listing| self.record(listing, Some(self.pr.commits), "commits"))
+			})
+			.await?;
+		Ok(commits)
+	}
+
+	/// Reviews submitted on the PR, oldest first.
+	// Loaded for rules to come; none reads reviews yet
+	#[allow(dead_code)]
+	pub async fn reviews(&self) -> crate::error::Result<&[PullRequestReview]> {
+		let reviews = self
+			.reviews
+			.get_or_try_init(|| async {
+				let listing = self.client()?.list_reviews(self.pr.number).await;
+				listing.map(|listing| self.record(listing, None, "reviews"))
+			})
+			.await?;
+		Ok(reviews)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use mockito::Server;
+
+	#[tokio::test]
+	async fn test_short_listing_is_truncated() {
+		let mut server = Server::new_async().await;
+		server
+			.mock("GET", "/repos/owner/repo/pulls/1/commits")
+			.match_query(mockito::Matcher::Any)
+			.with_body(r#"[{"sha": "a", "commit": {"message": "feat: a"}}]"#)
+			.create_async()
+			.await;
+		server
+			.mock("GET", "/repos/owner/repo/pulls/1/files")
+			.match_query(mockito::Matcher::Any)
+			.with_body(r#"[{"filename": "a.rs", "additions": 1, "deletions": 0}]"#)
+			.create_async()
+			.await;
+
+		server
+			.mock("GET", "/repos/owner/repo/pulls/1/reviews")
+			.match_query(mockito::Matcher::Any)
+			.with_body(r#"[{"state": "APPROVED", "user": {"login": "alice"}}]"#)
+			.create_async()
+			.await;
+
+		let client =
+			GitHubClient::new("t0ken".to_string(), "owner".to_string(), "repo".to_string())
+				.with_api_url(server.url());
+		let pr = PullRequest {
+			number: 1,
+			commits: 400,
+			changed_files: 1,
+			..Default::default()
+		};
+		let ctx = PrContext::new(pr).with_client(&client);
+		assert_eq!(ctx.commits().await.unwrap().len(), 1);
+		assert_eq!(ctx.files().await.unwrap().len(), 1);
+		assert_eq!(ctx.reviews().await.unwrap()[0].state, "APPROVED");
+		assert_eq!(ctx.truncated(), vec!["1 of 400 commits"]);
+	}
+}
@@ -0,0 +1,502 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional GraphQL loader fetching the PR, its labels, files, commits and
+//! reviews in a single request instead of one REST call per list page.
+
+use crate::github::pr::{
+	CommitDetails, CommitIdentity, CommitVerification, PullRequestLabel, PullRequestRef,
+	PullRequestUser,
+};
+use crate::github::{
+	GitHubClient, PullRequest, PullRequestCommit, PullRequestFile, PullRequestReview,
+};
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+
+const QUERY: &str = r#"
+query($owner: String!, $repo: String!, $number: Int!) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      number title body isDraft additions deletions changedFiles authorAssociation
+      author { login __typename }
+      baseRefName headRefName
+      labels(first: 100) { totalCount nodes { name } }
+      files(first: 100) { totalCount nodes { path additions deletions changeType } }
+      commits(first: 100) {
+        totalCount
+        nodes {
+          commit {
+            oid message
+            parents { totalCount }
+            author { name email user { login } }
+            signature { isValid state }
+          }
+        }
+      }
+      reviews(first: 100) { totalCount nodes { state author { login __typename } } }
+    }
+  }
+}
+"#;
+
+/// A PR as loaded by GraphQL. Lists that did not fit in one response are
+/// left out and fetched lazily over REST.
+#[derive(Debug)]
+pub struct PrSnapshot {
+	pub pr: PullRequest,
+	pub files: Option<Vec<PullRequestFile>>,
+	pub commits: Option<Vec<PullRequestCommit>>,
+	pub reviews: Option<Vec<PullRequestReview>>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+	data: Option<Data>,
+	#[serde(default)]
+	errors: Vec<GraphQlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlError {
+	message: String,
+}
+
+#[derive(Deserialize)]
+struct Data {
+	repository: Option<RepositoryNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepositoryNode {
+	pull_request: Option<PullRequestNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestNode {
+	number: u64,
+	title: String,
+	body: String,
+	is_draft: bool,
+	additions: u64,
+	deletions: u64,
+	changed_files: u64,
+	author_association: String,
+	/// `null` for deleted accounts
+	author: Option<ActorNode>,
+	base_ref_name: String,
+	head_ref_name: String,
+	labels: Connection<LabelNode>,
+	files: Connection<FileNode>,
+	commits: Connection<CommitNode>,
+	reviews: Connection<ReviewNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<T> {
+	total_count: usize,
+	nodes: Vec<T>,
+}
+
+impl<T> Connection<T> {
+	fn is_complete(&self) -> bool {
+		self.nodes.len() >= self.total_count
+	}
+}
+
+#[derive(Deserialize)]
+struct ActorNode {
+	login: String,
+	/// `User` or `Bot`, like the REST `type`
+	#[serde(rename = "__typename")]
+	typename: String,
+}
+
+impl From<ActorNode> for PullRequestUser {
+	fn from(actor: ActorNode) -> Self {
+		Self {
+			// REST logins of apps carry a `[bot]` suffix that GraphQL
+			// leaves off, and configs match against the REST form
+			login: if actor.typename == "Bot" {
+				format!("{}[bot]", actor.login)
+			} else {
+				actor.login
+			},
+			kind: actor.typename,
+		}
+	}
+}
+
+#[derive(Deserialize)]
+struct LabelNode {
+	name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileNode {
+	path: String,
+	additions: u64,
+	deletions: u64,
+	/// e.g. `MODIFIED`, `DELETED` or `RENAMED`
+	change_type: String,
+}
+
+#[derive(Deserialize)]
+struct ReviewNode {
+	/// Same values as the REST `state`, e.g. `APPROVED`
+	state: String,
+	author: Option<ActorNode>,
+}
+
+#[derive(Deserialize)]
+struct CommitNode {
+	commit: GitCommitNode,
+}
+
+#[derive(Deserialize)]
+struct GitCommitNode {
+	oid: String,
+	message: String,
+	parents: TotalCount,
+	author: Option<GitActorNode>,
+	signature: Option<SignatureNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TotalCount {
+	total_count: usize,
+}
+
+#[derive(Deserialize)]
+struct GitActorNode {
+	name: Option<String>,
+	email: Option<String>,
+	user: Option<UserNode>,
+}
+
+#[derive(Deserialize)]
+struct UserNode {
+	login: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureNode {
+	is_valid: bool,
+	/// e.g. `VALID` or `UNKNOWN_KEY`, the upper-case REST `reason`
+	state: String,
+}
+
+impl GitHubClient {
+	/// Load the PR with its files and commits in one GraphQL request.
+	pub async fn load_pr_graphql(&self, pr_number: u64) -> crate::error::Result<PrSnapshot> {
+		let (owner, repo) = self.owner_and_repo();
+		let body = serde_json::json!({
+			"query": QUERY,
+			"variables": { "owner": owner, "repo": repo, "number": pr_number },
+		});
+		let response: Response = self
+			.post_json(&self.graphql_url(), &body, "query PR over GraphQL")
+			.await?;
+
+		if !response.errors.is_empty() {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"GraphQL query failed: {}",
+				response
+					.errors
+					.iter()
+					.map(|e| e.message.as_str())
+					.collect::<Vec<_>>()
+					.join("; ")
+			)));
+		}
+		let node = response
+			.data
+			.and_then(|d| d.repository)
+			.and_then(|r| r.pull_request)
+			.ok_or_else(|| {
+				crate::error::Error::GitHubApi(format!(
+					"PR #{} not found in GraphQL response",
+					pr_number
+				))
+			})?;
+
+		// Labels live on the PR itself, so a partial list cannot be patched
+		// up lazily
+		if !node.labels.is_complete() {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"PR #{} has more than {} labels",
+				pr_number,
+				node.labels.nodes.len()
+			)));
+		}
+
+		Ok(snapshot(node, self.max_items()))
+	}
+}
+
+fn snapshot(node: PullRequestNode, max_items: usize) -> PrSnapshot {
+	// GraphQL does not report the old path of renamed files, which the
+	// `files` rule needs, so renames are left to REST
+	let files = (node.files.is_complete()
+		&& node.files.total_count <= max_items
+		&& !node.files.nodes.iter().any(|f| f.change_type == "RENAMED"))
+	.then(|| {
+		node.files
+			.nodes
+			.into_iter()
+			.map(|f| PullRequestFile {
+				filename: f.path,
+				previous_filename: None,
+				additions: f.additions,
+				deletions: f.deletions,
+				// The REST names for the change types
+				status: match f.change_type.as_str() {
+					"DELETED" => "removed".to_string(),
+					other => other.to_lowercase(),
+				},
+			})
+			.collect()
+	});
+
+	let commit_count = node.commits.total_count as u64;
+	let commits =
+		(node.commits.is_complete() && node.commits.total_count <= max_items).then(|| {
+			node.commits
+				.nodes
+				.into_iter()
+				.map(|c| to_commit(c.commit))
+				.collect()
+		});
+
+	let reviews =
+		(node.reviews.is_complete() && node.reviews.total_count <= max_items).then(|| {
+			node.reviews
+				.nodes
+				.into_iter()
+				.map(|r| PullRequestReview {
+					state: r.state,
+					user: r.author.map(PullRequestUser::from),
+				})
+				.collect()
+		});
+
+	let pr = PullRequest {
+		number: node.number,
+		title: node.title,
+		body: Some(node.body).filter(|b| !b.is_empty()),
+		labels: node
+			.labels
+			.nodes
+			.into_iter()
+			.map(|l| PullRequestLabel { name: l.name })
+			.collect(),
+		additions: node.additions,
+		deletions: node.deletions,
+		changed_files: node.changed_files,
+		commits: commit_count,
+		user: node.author.map(PullRequestUser::from).unwrap_or_default(),
+		author_association: node.author_association,
+		base: PullRequestRef {
+			name: node.base_ref_name,
+		},
+		head: PullRequestRef {
+			name: node.head_ref_name,
+		},
+		draft: node.is_draft,
+	};
+
+	PrSnapshot {
+		pr,
+		files,
+		commits,
+		reviews,
+	}
+}
+
+fn to_commit(commit: GitCommitNode) -> PullRequestCommit {
+	let author = commit.author;
+	PullRequestCommit {
+		sha: commit.oid,
+		author: author
+			.as_ref()
+			.and_then(|a| a.user.as_ref())
+			.map(|u| PullRequestUser {
+				login: u.login.clone(),
+				kind: "User".to_string(),
+			}),
+		parents: vec![IgnoredAny; commit.parents.total_count],
+		commit: CommitDetails {
+			message: commit.message,
+			author: author
+				.map(|a| CommitIdentity {
+					name: a.name.unwrap_or_default(),
+					email: a.email.unwrap_or_default(),
+				})
+				.unwrap_or_default(),
+			verification: Some(match commit.signature {
+				Some(signature) => CommitVerification {
+					verified: signature.is_valid && signature.state == "VALID",
+					reason: signature.state.to_lowercase(),
+				},
+				None => CommitVerification {
+					verified: false,
+					reason: "unsigned".to_string(),
+				},
+			}),
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::config::Exemptions;
+	use crate::rules::exemption::exemption_reason;
+	use mockito::{Matcher, Server};
+
+	const RESPONSE: &str = r#"{"data": {"repository": {"pullRequest": {
+		"number": 7, "title": "feat: graphql", "body": "", "isDraft": false,
+		"additions": 3, "deletions": 1, "changedFiles": 2, "authorAssociation": "MEMBER",
+		"author": {"login": "renovate", "__typename": "Bot"},
+		"baseRefName": "main", "headRefName": "feat/graphql",
+		"labels": {"totalCount": 1, "nodes": [{"name": "kind/feature"}]},
+		"files": {"totalCount": 2, "nodes": [
+			{"path": "src/a.rs", "additions": 2, "deletions": 1, "changeType": "MODIFIED"},
+			{"path": "src/b.rs", "additions": 1, "deletions": 0, "changeType": "ADDED"}
+		]},
+		"commits": {"totalCount": 2, "nodes": [
+			{"commit": {"oid": "abc", "message": "feat: graphql", "parents": {"totalCount": 1},
+				"author": {"name": "Jane", "email": "jane@example.com", "user": {"login": "jane"}},
+				"signature": {"isValid": true, "state": "VALID"}}},
+			{"commit": {"oid": "def", "message": "fix: typo", "parents": {"totalCount": 1},
+				"author": {"name": "Jane", "email": "jane@example.com", "user": null},
+				"signature": null}}
+		]},
+		"reviews": {"totalCount": 1, "nodes": [
+			{"state": "APPROVED", "author": {"login": "copilot", "__typename": "Bot"}}
+		]}
+	}}}}"#;
+
+	fn create_client(server: &Server) -> GitHubClient {
+		GitHubClient::new("t0ken".to_string(), "owner".to_string(), "repo".to_string())
+			.with_api_url(format!("{}/api/v3", server.url()))
+	}
+
+	#[test]
+	fn test_graphql_url() {
+		let client = GitHubClient::new(String::new(), "o".to_string(), "r".to_string());
+		assert_eq!(client.graphql_url(), "https://api.github.com/graphql");
+		let client = client.with_api_url("https://github.example.com/api/v3");
+		assert_eq!(
+			client.graphql_url(),
+			"https://github.example.com/api/graphql"
+		);
+	}
+
+	#[tokio::test]
+	async fn test_load_pr() {
+		let mut server = Server::new_async().await;
+		let mock = server
+			.mock("POST", "/api/graphql")
+			.match_body(Matcher::PartialJson(serde_json::json!({
+				"variables": { "owner": "owner", "repo": "repo", "number": 7 }
+			})))
+			.with_body(RESPONSE)
+			.create_async()
+			.await;
+
+		let snapshot = create_client(&server).load_pr_graphql(7).await.unwrap();
+		mock.assert_async().await;
+
+		assert_eq!(snapshot.pr.title, "feat: graphql");
+		assert_eq!(snapshot.pr.body, None);
+		assert_eq!(snapshot.pr.user.login, "renovate[bot]");
+		assert_eq!(snapshot.pr.user.kind, "Bot");
+		assert_eq!(snapshot.pr.head.name, "feat/graphql");
+		assert_eq!(snapshot.pr.labels[0].name, "kind/feature");
+		let files = snapshot.files.unwrap();
+		assert_eq!(files.len(), 2);
+		assert_eq!(files[1].status, "added");
+
+		let commits = snapshot.commits.unwrap();
+		assert_eq!(commits[0].author.as_ref().unwrap().login, "jane");
+		assert!(commits[0].commit.verification.as_ref().unwrap().verified);
+		let unsigned = commits[1].commit.verification.as_ref().unwrap();
+		assert_eq!(unsigned.reason, "unsigned");
+
+		let reviews = snapshot.reviews.unwrap();
+		assert_eq!(reviews[0].state, "APPROVED");
+		assert_eq!(reviews[0].user.as_ref().unwrap().login, "copilot[bot]");
+	}
+
+	#[tokio::test]
+	async fn test_bot_author_matches_rest_login() {
+		let mut server = Server::new_async().await;
+		server
+			.mock("POST", "/api/graphql")
+			.with_body(RESPONSE)
+			.create_async()
+			.await;
+
+		let snapshot = create_client(&server).load_pr_graphql(7).await.unwrap();
+		let exemptions: Exemptions = serde_yaml::from_str("authors: [\"renovate[bot]\"]").unwrap();
+		assert_eq!(
+			exemption_reason(&exemptions, &snapshot.pr).as_deref(),
+			Some("author 'renovate[bot]' is exempt")
+		);
+	}
+
+	#[tokio::test]
+	async fn test_partial_lists_are_left_to_rest() {
+		let mut server = Server::new_async().await;
+		let response = RESPONSE.replace(r#""changeType": "ADDED""#, r#""changeType": "RENAMED""#);
+		server
+			.mock("POST", "/api/graphql")
+			.with_body(response)
+			.create_async()
+			.await;
+
+		let snapshot = create_client(&server).load_pr_graphql(7).await.unwrap();
+		assert!(snapshot.files.is_none());
+		assert!(snapshot.commits.is_some());
+
+		let snapshot = create_client(&server)
+			.with_max_items(1)
+			.load_pr_graphql(7)
+			.await
+			.unwrap();
+		assert!(snapshot.commits.is_none());
+	}
+
+	#[tokio::test]
+	async fn test_errors() {
+		let mut server = Server::new_async().await;
+		server
+			.mock("POST", "/api/graphql")
+			.with_body(
+				r#"{"data": null, "errors": [{"message": "Resource not accessible by integration"}]}"#,
+			)
+			.create_async()
+			.await;
+
+		let error = create_client(&server).load_pr_graphql(7).await.unwrap_err();
+		assert!(error.to_string().contains("not accessible"));
+	}
+}
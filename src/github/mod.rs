@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod app;
+pub mod context;
+pub mod graphql;
 pub mod pr;
+pub mod retry;
 
-pub use pr::{GitHubClient, PullRequest};
+pub use context::PrContext;
+pub use pr::{GitHubClient, PullRequest, PullRequestCommit, PullRequestFile, PullRequestReview};
+pub use retry::RetryPolicy;
@@ -12,13 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::github::app::AppAuth;
+use crate::github::retry::{self, RetryPolicy};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use tracing::{info, warn};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct PullRequest {
 	pub number: u64,
 	pub title: String,
+	pub body: Option<String>,
 	pub labels: Vec<PullRequestLabel>,
+	/// Lines added across the whole PR
+	pub additions: u64,
+	/// Lines removed across the whole PR
+	pub deletions: u64,
+	pub changed_files: u64,
+	pub commits: u64,
+	/// Author of the PR
+	pub user: PullRequestUser,
+	/// Author's relationship to the repository, e.g. `MEMBER`
+	#[serde(default)]
+	pub author_association: String,
+	/// Branch the PR is merged into
+	pub base: PullRequestRef,
+	/// Branch the PR is merged from
+	pub head: PullRequestRef,
+	#[serde(default)]
+	pub draft: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -26,6 +51,136 @@ pub struct PullRequestLabel {
 	pub name: String,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PullRequestUser {
+	pub login: String,
+	/// Account type, `User` or `Bot`
+	#[serde(rename = "type", default)]
+	pub kind: String,
+}
+
+/// A submitted review. No rule reads reviews yet.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequestReview {
+	/// e.g. `APPROVED`, `CHANGES_REQUESTED` or `COMMENTED`
+	pub state: String,
+	/// `null` for deleted accounts
+	pub user: Option<PullRequestUser>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PullRequestRef {
+	/// Branch name, e.g. `main`
+	#[serde(rename = "ref")]
+	pub name: String,
+}
+
+/// A file changed by the pull request, from `/pulls/{n}/files`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PullRequestFile {
+	pub filename: String,
+	/// Former path when the file was renamed
+	pub previous_filename: Option<String>,
+	pub additions: u64,
+	pub deletions: u64,
+	/// e.g. `added`, `modified`, `removed` or `renamed`
+	#[serde(default)]
+	pub status: String,
+}
+
+impl PullRequestFile {
+	pub fn is_removed(&self) -> bool {
+		self.status == "removed"
+	}
+}
+
+/// A commit in the pull request, from `/pulls/{n}/commits`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PullRequestCommit {
+	pub sha: String,
+	pub commit: CommitDetails,
+	/// GitHub account of the commit author, when the email is linked to one
+	pub author: Option<PullRequestUser>,
+	/// Parent commits, only counted to detect merge commits
+	#[serde(default)]
+	pub parents: Vec<serde::de::IgnoredAny>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommitDetails {
+	pub message: String,
+	#[serde(default)]
+	pub author: CommitIdentity,
+	/// Signature verification result
+	pub verification: Option<CommitVerification>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommitVerification {
+	pub verified: bool,
+	/// Why verification failed, e.g. `unsigned` or `bad_email`; `valid` when verified
+	pub reason: String,
+}
+
+/// Git author or committer identity.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CommitIdentity {
+	pub name: String,
+	pub email: String,
+}
+
+impl PullRequestCommit {
+	/// Abbreviated SHA as shown by `git log --oneline`.
+	pub fn short_sha(&self) -> &str {
+		self.sha.get(..7).unwrap_or(&self.sha)
+	}
+
+	/// First line of the commit message.
+	pub fn subject(&self) -> &str {
+		self.commit.message.lines().next().unwrap_or_default()
+	}
+
+	pub fn is_merge(&self) -> bool {
+		self.parents.len() > 1
+	}
+}
+
+/// An issue as returned by `/issues/{n}`; pull requests are issues too.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Issue {
+	/// `open` or `closed`
+	pub state: String,
+	/// Only present when the issue is a pull request
+	#[serde(default)]
+	pub pull_request: Option<serde::de::IgnoredAny>,
+}
+
+impl Issue {
+	pub fn is_pull_request(&self) -> bool {
+		self.pull_request.is_some()
+	}
+}
+
+/// REST API root on github.com; GitHub Enterprise Server uses
+/// `https://HOST/api/v3`.
+const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// Page size requested from list endpoints (the API maximum).
+const PER_PAGE: usize = 100;
+
+/// Default cap on items fetched from one list endpoint; the files endpoint
+/// stops at 3000 files anyway.
+const DEFAULT_MAX_ITEMS: usize = 3000;
+
+/// Items fetched from a list endpoint, cut off at the client's `max_items`.
+#[derive(Debug)]
+pub struct Listing<T> {
+	pub items: Vec<T>,
+	/// More items exist beyond the cap
+	pub truncated: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct GitHubEvent {
 	#[serde(rename = "pull_request")]
@@ -44,27 +199,47 @@ struct Owner {
 	login: String,
 }
 
+/// How requests authenticate.
+enum Credentials {
+	Token(String),
+	App(AppAuth),
+}
+
 pub struct GitHubClient {
 	client: reqwest::Client,
-	token: String,
+	api_url: String,
+	retry: RetryPolicy,
+	max_items: usize,
+	credentials: Credentials,
 	owner: String,
 	repo: String,
 }
 
 impl GitHubClient {
 	pub fn new(token: String, owner: String, repo: String) -> Self {
+		Self::with_credentials(Credentials::Token(token), owner, repo)
+	}
+
+	/// Authenticate with installation tokens of a GitHub App.
+	pub fn from_app(app: AppAuth, owner: String, repo: String) -> Self {
+		Self::with_credentials(Credentials::App(app), owner, repo)
+	}
+
+	fn with_credentials(credentials: Credentials, owner: String, repo: String) -> Self {
 		Self {
 			client: reqwest::Client::new(),
-			token,
+			api_url: DEFAULT_API_URL.to_string(),
+			retry: RetryPolicy::default(),
+			max_items: DEFAULT_MAX_ITEMS,
+			credentials,
 			owner,
 			repo,
 		}
 	}
 
+	/// Authenticate as the GitHub App configured in the environment, or with
+	/// `GITHUB_TOKEN` when there is none.
 	pub fn from_env() -> crate::error::Result<Self> {
-		let token = std::env::var("GITHUB_TOKEN")
-			.map_err(|_| crate::error::Error::Config("GITHUB_TOKEN not set".to_string()))?;
-
 		let event_path = std::env::var("GITHUB_EVENT_PATH")
 			.map_err(|_| crate::error::Error::Config("GITHUB_EVENT_PATH not set".to_string()))?;
 
@@ -79,33 +254,322 @@ impl GitHubClient {
 			));
 		};
 
-		Ok(Self::new(token, owner, repo))
+		let client = match AppAuth::from_env()? {
+			Some(app) => {
+				info!("Authenticating as GitHub App {}", app.app_id());
+				Self::from_app(app, owner, repo)
+			}
+			None => {
+				let token = std::env::var("GITHUB_TOKEN")
+					.map_err(|_| crate::error::Error::Config("GITHUB_TOKEN not set".to_string()))?;
+				Self::new(token, owner, repo)
+			}
+		};
+		// Set by Actions runners, pointing at the GHES API on Enterprise Server
+		match std::env::var("GITHUB_API_URL") {
+			Ok(url) if !url.is_empty() => Ok(client.with_api_url(url)),
+			_ => Ok(client),
+		}
 	}
 
-	pub async fn get_pr(&self, pr_number: u64) -> crate::error::Result<PullRequest> {
-		let url = format!(
-			"https://api.github.com/repos/{}/{}/pulls/{}",
-			self.owner, self.repo, pr_number
-		);
+	/// Use another REST API root, e.g. `https://github.example.com/api/v3`.
+	pub fn with_api_url(mut self, url: impl Into<String>) -> Self {
+		self.api_url = url.into().trim_end_matches('/').to_string();
+		self
+	}
 
-		let response = self
-			.client
-			.get(&url)
-			.header("Authorization", format!("Bearer {}", self.token))
+	pub fn api_url(&self) -> &str {
+		&self.api_url
+	}
+
+	pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+		self.retry = retry;
+		self
+	}
+
+	/// Stop list requests after `max_items` items.
+	pub fn with_max_items(mut self, max_items: usize) -> Self {
+		self.max_items = max_items;
+		self
+	}
+
+	fn repo_url(&self, path: &str) -> String {
+		format!(
+			"{}/repos/{}/{}/{}",
+			self.api_url, self.owner, self.repo, path
+		)
+	}
+
+	pub(super) fn request(&self, method: Method, url: &str) -> reqwest::RequestBuilder {
+		self.client
+			.request(method, url)
 			.header("Accept", "application/vnd.github.v3+json")
 			.header("User-Agent", "pr-checker")
-			.send()
+	}
+
+	/// Bearer token for API requests, minting a new installation token for
+	/// GitHub Apps when needed.
+	pub(super) async fn token(&self) -> crate::error::Result<String> {
+		match &self.credentials {
+			Credentials::Token(token) => Ok(token.clone()),
+			Credentials::App(app) => app.installation_token(self).await,
+		}
+	}
+
+	/// Send a request with the client's credentials.
+	async fn send(
+		&self,
+		request: reqwest::RequestBuilder,
+	) -> crate::error::Result<reqwest::Response> {
+		let token = self.token().await?;
+		self.send_as(request, &token).await
+	}
+
+	/// Send a request authenticated with `bearer`, retrying server errors,
+	/// rate limits and network failures according to the retry policy.
+	pub(super) async fn send_as(
+		&self,
+		request: reqwest::RequestBuilder,
+		bearer: &str,
+	) -> crate::error::Result<reqwest::Response> {
+		let mut attempt = 0;
+		loop {
+			let current = request
+				.try_clone()
+				.expect("GitHub API requests have buffered bodies")
+				.bearer_auth(bearer);
+			let delay = match current.send().await {
+				Ok(response) => {
+					retry::log_quota(response.headers());
+					let Some(delay) =
+						self.retry
+							.retry_delay(response.status(), response.headers(), attempt)
+					else {
+						return Ok(response);
+					};
+					warn!(
+						"GitHub API returned {} for {}, retrying in {:.1}s ({}/{})",
+						response.status(),
+						response.url(),
+						delay.as_secs_f64(),
+						attempt + 1,
+						self.retry.max_retries
+					);
+					delay
+				}
+				Err(e) if attempt < self.retry.max_retries && retry::is_transient(&e) => {
+					let delay = self.retry.backoff(attempt);
+					warn!(
+						"GitHub API request failed: {}, retrying in {:.1}s ({}/{})",
+						e,
+						delay.as_secs_f64(),
+						attempt + 1,
+						self.retry.max_retries
+					);
+					delay
+				}
+				Err(e) => return Err(e.into()),
+			};
+			tokio::time::sleep(delay).await;
+			attempt += 1;
+		}
+	}
+
+	/// GraphQL endpoint next to the REST root: `https://api.github.com/graphql`,
+	/// or `https://HOST/api/graphql` on Enterprise Server.
+	pub(super) fn graphql_url(&self) -> String {
+		let root = self.api_url.strip_suffix("/v3").unwrap_or(&self.api_url);
+		format!("{}/graphql", root)
+	}
+
+	pub(super) fn owner_and_repo(&self) -> (&str, &str) {
+		(&self.owner, &self.repo)
+	}
+
+	pub(super) fn max_items(&self) -> usize {
+		self.max_items
+	}
+
+	/// POST a JSON body to `url`, failing on non-success statuses.
+	pub(super) async fn post_json<T: DeserializeOwned>(
+		&self,
+		url: &str,
+		body: &serde_json::Value,
+		what: &str,
+	) -> crate::error::Result<T> {
+		let response = self
+			.send(self.request(Method::POST, url).json(body))
+			.await?;
+
+		if !response.status().is_success() {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"Failed to {}: {}",
+				what,
+				response.status()
+			)));
+		}
+
+		Ok(response.json().await?)
+	}
+
+	/// GET `url`, failing on non-success statuses.
+	async fn get(&self, url: &str, what: &str) -> crate::error::Result<reqwest::Response> {
+		let response = self.send(self.request(Method::GET, url)).await?;
+
+		if !response.status().is_success() {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"Failed to {}: {}",
+				what,
+				response.status()
+			)));
+		}
+
+		Ok(response)
+	}
+
+	async fn get_json<T: DeserializeOwned>(
+		&self,
+		url: &str,
+		what: &str,
+	) -> crate::error::Result<T> {
+		Ok(self.get(url, what).await?.json().await?)
+	}
+
+	pub async fn get_pr(&self, pr_number: u64) -> crate::error::Result<PullRequest> {
+		let url = self.repo_url(&format!("pulls/{}", pr_number));
+		self.get_json(&url, "fetch PR").await
+	}
+
+	/// Every item of a list endpoint, following `Link: rel="next"` headers
+	/// and fetching each page only when the stream reaches it.
+	pub fn paginate<'c, T: DeserializeOwned + 'c>(
+		&'c self,
+		path: &str,
+		what: &'c str,
+	) -> impl Stream<Item = crate::error::Result<T>> + 'c {
+		let first = self.repo_url(&format!("{}?per_page={}", path, PER_PAGE));
+		stream::try_unfold(Some(first), move |url| async move {
+			match url {
+				Some(url) => self
+					.get_page(&url, what)
+					.await
+					.map(|(items, next)| Some((stream::iter(items.into_iter().map(Ok)), next))),
+				None => Ok(None),
+			}
+		})
+		.try_flatten()
+	}
+
+	/// One page of a list endpoint and the URL of the next page, if any.
+	async fn get_page<T: DeserializeOwned>(
+		&self,
+		url: &str,
+		what: &str,
+	) -> crate::error::Result<(Vec<T>, Option<String>)> {
+		let response = self.get(url, what).await?;
+		let next = next_link(response.headers());
+		Ok((response.json().await?, next))
+	}
+
+	/// Collect a list endpoint, stopping after `max_items` items.
+	async fn list<T: DeserializeOwned>(
+		&self,
+		path: &str,
+		what: &str,
+	) -> crate::error::Result<Listing<T>> {
+		// One extra item tells whether the cap cut anything off
+		let mut items: Vec<T> = self
+			.paginate(path, what)
+			.take(self.max_items.saturating_add(1))
+			.try_collect()
 			.await?;
+		let truncated = items.len() > self.max_items;
+		items.truncate(self.max_items);
+		Ok(Listing { items, truncated })
+	}
+
+	/// Files changed by the PR, following pagination.
+	pub async fn list_files(
+		&self,
+		pr_number: u64,
+	) -> crate::error::Result<Listing<PullRequestFile>> {
+		self.list(&format!("pulls/{}/files", pr_number), "list PR files")
+			.await
+	}
+
+	/// Commits in the PR, oldest first, following pagination.
+	pub async fn list_commits(
+		&self,
+		pr_number: u64,
+	) -> crate::error::Result<Listing<PullRequestCommit>> {
+		self.list(&format!("pulls/{}/commits", pr_number), "list PR commits")
+			.await
+	}
+
+	/// Reviews submitted on the PR, oldest first, following pagination.
+	pub async fn list_reviews(
+		&self,
+		pr_number: u64,
+	) -> crate::error::Result<Listing<PullRequestReview>> {
+		self.list(&format!("pulls/{}/reviews", pr_number), "list PR reviews")
+			.await
+	}
+
+	/// Fetch an issue, returning `None` when it does not exist.
+	pub async fn get_issue(&self, number: u64) -> crate::error::Result<Option<Issue>> {
+		let url = self.repo_url(&format!("issues/{}", number));
+		let response = self.send(self.request(Method::GET, &url)).await?;
+
+		match response.status() {
+			StatusCode::NOT_FOUND | StatusCode::GONE => Ok(None),
+			status if status.is_success() => Ok(Some(response.json().await?)),
+			status => Err(crate::error::Error::GitHubApi(format!(
+				"Failed to fetch issue #{}: {}",
+				number, status
+			))),
+		}
+	}
+
+	/// Add labels to a PR (PRs share the issues labels API).
+	pub async fn add_labels(&self, pr_number: u64, labels: &[String]) -> crate::error::Result<()> {
+		let url = self.repo_url(&format!("issues/{}/labels", pr_number));
+		let request = self
+			.request(Method::POST, &url)
+			.json(&serde_json::json!({ "labels": labels }));
+		let response = self.send(request).await?;
 
 		if !response.status().is_success() {
 			return Err(crate::error::Error::GitHubApi(format!(
-				"Failed to fetch PR: {}",
+				"Failed to add labels: {}",
 				response.status()
 			)));
 		}
+		Ok(())
+	}
+
+	/// Remove a label from a PR; a label that is already gone is not an error.
+	pub async fn remove_label(&self, pr_number: u64, label: &str) -> crate::error::Result<()> {
+		let base = self.repo_url(&format!("issues/{}/labels", pr_number));
+		let mut url = reqwest::Url::parse(&base).map_err(|e| {
+			crate::error::Error::GitHubApi(format!("Invalid URL '{}': {}", base, e))
+		})?;
+		// Push as a path segment so label names like `size/XS` are escaped
+		url.path_segments_mut()
+			.map_err(|_| crate::error::Error::GitHubApi(format!("Invalid URL '{}'", base)))?
+			.push(label);
 
-		let pr: PullRequest = response.json().await?;
-		Ok(pr)
+		let response = self
+			.send(self.request(Method::DELETE, url.as_str()))
+			.await?;
+
+		let status = response.status();
+		if !status.is_success() && status != StatusCode::NOT_FOUND {
+			return Err(crate::error::Error::GitHubApi(format!(
+				"Failed to remove label '{}': {}",
+				label, status
+			)));
+		}
+		Ok(())
 	}
 
 	pub fn parse_pr_number_from_event() -> crate::error::Result<u64> {
@@ -124,3 +588,279 @@ impl GitHubClient {
 		}
 	}
 }
+
+/// URL of the `rel="next"` entry in a `Link` header, e.g.
+/// `<https://api.github.com/...&page=2>; rel="next", <...>; rel="last"`.
+fn next_link(headers: &HeaderMap) -> Option<String> {
+	let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+	link.split(',').find_map(|entry| {
+		let (url, params) = entry.split_once(';')?;
+		params
+			.split(';')
+			.any(|p| p.trim() == r#"rel="next""#)
+			.then(|| {
+				url.trim()
+					.trim_start_matches('<')
+					.trim_end_matches('>')
+					.to_string()
+			})
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use mockito::{Matcher, Server};
+	use std::time::Duration;
+
+	/// A client for `owner/repo` talking to `server` under a GHES-style
+	/// `/api/v3` prefix.
+	fn create_client(server: &Server) -> GitHubClient {
+		GitHubClient::new("t0ken".to_string(), "owner".to_string(), "repo".to_string())
+			.with_api_url(format!("{}/api/v3/", server.url()))
+			.with_retry_policy(RetryPolicy {
+				max_retries: 2,
+				initial_backoff: Duration::ZERO,
+				max_backoff: Duration::from_secs(1),
+			})
+	}
+
+	#[test]
+	fn test_default_api_url() {
+		let client = GitHubClient::new(String::new(), "o".to_string(), "r".to_string());
+		assert_eq!(
+			client.repo_url("pulls/1"),
+			"https://api.github.com/repos/o/r/pulls/1"
+		);
+	}
+
+	#[tokio::test]
+	async fn test_get_pr() {
+		let mut server = Server::new_async().await;
+		let mock = server
+			.mock("GET", "/api/v3/repos/owner/repo/pulls/7")
+			.match_header("authorization", "Bearer t0ken")
+			.with_header("content-type", "application/json")
+			.with_body(
+				r#"{"number": 7, "title": "feat: ghes", "body": null, "labels": [{"name": "kind/feature"}],
+				"additions": 1, "deletions": 2, "changed_files": 1, "commits": 1,
+				"user": {"login": "jane", "type": "User"}, "base": {"ref": "main"},
+				"head": {"ref": "feat/ghes"}, "draft": true}"#,
+			)
+			.create_async()
+			.await;
+
+		let pr = create_client(&server).get_pr(7).await.unwrap();
+		assert_eq!(pr.title, "feat: ghes");
+		assert_eq!(pr.base.name, "main");
+		assert!(pr.draft);
+		mock.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_list_files_follows_links() {
+		let mut server = Server::new_async().await;
+		let path = "/api/v3/repos/owner/repo/pulls/7/files";
+		let file = |name: &str| {
+			format!(
+				r#"{{"filename": "{}", "additions": 1, "deletions": 0}}"#,
+				name
+			)
+		};
+		let first = server
+			.mock("GET", path)
+			.match_query(Matcher::Exact("per_page=100".into()))
+			.with_header(
+				"link",
+				&format!(
+					r#"<{0}{1}?per_page=100&page=2>; rel="next", <{0}{1}?per_page=100&page=2>; rel="last""#,
+					server.url(),
+					path
+				),
+			)
+			.with_body(format!("[{}, {}]", file("a.rs"), file("b.rs")))
+			.create_async()
+			.await;
+		let second = server
+			.mock("GET", path)
+			.match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+			.with_body(format!("[{}]", file("c.rs")))
+			.create_async()
+			.await;
+
+		let listing = create_client(&server).list_files(7).await.unwrap();
+		let names: Vec<&str> = listing.items.iter().map(|f| f.filename.as_str()).collect();
+		assert_eq!(names, vec!["a.rs", "b.rs", "c.rs"]);
+		assert!(!listing.truncated);
+		first.assert_async().await;
+		second.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_list_is_capped() {
+		let mut server = Server::new_async().await;
+		let commit =
+			|sha: &str| format!(r#"{{"sha": "{}", "commit": {{"message": "fix: x"}}}}"#, sha);
+		server
+			.mock("GET", "/api/v3/repos/owner/repo/pulls/7/commits")
+			.match_query(Matcher::Any)
+			.with_body(format!(
+				"[{}, {}, {}]",
+				commit("a"),
+				commit("b"),
+				commit("c")
+			))
+			.create_async()
+			.await;
+
+		let client = create_client(&server).with_max_items(2);
+		let listing = client.list_commits(7).await.unwrap();
+		assert_eq!(listing.items.len(), 2);
+		assert!(listing.truncated);
+
+		let listing = client.with_max_items(3).list_commits(7).await.unwrap();
+		assert_eq!(listing.items.len(), 3);
+		assert!(!listing.truncated);
+
+		let client = create_client(&server).with_max_items(usize::MAX);
+		let listing = client.list_commits(7).await.unwrap();
+		assert_eq!(listing.items.len(), 3);
+		assert!(!listing.truncated);
+	}
+
+	#[test]
+	fn test_next_link() {
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			reqwest::header::LINK,
+			r#"<https://api.github.com/x?page=1>; rel="prev", <https://api.github.com/x?page=3>; rel="next""#
+				.parse()
+				.unwrap(),
+		);
+		assert_eq!(
+			next_link(&headers).as_deref(),
+			Some("https://api.github.com/x?page=3")
+		);
+		assert_eq!(next_link(&HeaderMap::new()), None);
+	}
+
+	#[tokio::test]
+	async fn test_get_issue() {
+		let mut server = Server::new_async().await;
+		server
+			.mock("GET", "/api/v3/repos/owner/repo/issues/1")
+			.with_body(r#"{"state": "closed"}"#)
+			.create_async()
+			.await;
+		server
+			.mock("GET", "/api/v3/repos/owner/repo/issues/2")
+			.with_status(404)
+			.create_async()
+			.await;
+		server
+			.mock("GET", "/api/v3/repos/owner/repo/issues/3")
+			.with_status(403)
+			.create_async()
+			.await;
+
+		let client = create_client(&server);
+		assert_eq!(client.get_issue(1).await.unwrap().unwrap().state, "closed");
+		assert!(client.get_issue(2).await.unwrap().is_none());
+		assert!(client.get_issue(3).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn test_retries_server_errors() {
+		let mut server = Server::new_async().await;
+		let failing = server
+			.mock("GET", "/api/v3/repos/owner/repo/issues/1")
+			.with_status(502)
+			.expect(2)
+			.create_async()
+			.await;
+		let ok = server
+			.mock("GET", "/api/v3/repos/owner/repo/issues/1")
+			.with_header("x-ratelimit-remaining", "4999")
+			.with_header("x-ratelimit-limit", "5000")
+			.with_body(r#"{"state": "open"}"#)
+			.create_async()
+			.await;
+
+		let issue = create_client(&server).get_issue(1).await.unwrap();
+		assert_eq!(issue.unwrap().state, "open");
+		failing.assert_async().await;
+		ok.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_retries_are_limited() {
+		let mut server = Server::new_async().await;
+		let failing = server
+			.mock("GET", "/api/v3/repos/owner/repo/pulls/7")
+			.with_status(503)
+			.expect(3)
+			.create_async()
+			.await;
+
+		assert!(create_client(&server).get_pr(7).await.is_err());
+		failing.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_retries_rate_limits() {
+		let mut server = Server::new_async().await;
+		let limited = server
+			.mock("POST", "/api/v3/repos/owner/repo/issues/7/labels")
+			.with_status(403)
+			.with_header("retry-after", "0")
+			.expect(1)
+			.create_async()
+			.await;
+		let ok = server
+			.mock("POST", "/api/v3/repos/owner/repo/issues/7/labels")
+			.match_body(Matcher::Json(serde_json::json!({ "labels": ["a"] })))
+			.create_async()
+			.await;
+		let forbidden = server
+			.mock("GET", "/api/v3/repos/owner/repo/issues/2")
+			.with_status(403)
+			.expect(1)
+			.create_async()
+			.await;
+
+		let client = create_client(&server);
+		client.add_labels(7, &["a".to_string()]).await.unwrap();
+		assert!(client.get_issue(2).await.is_err());
+		limited.assert_async().await;
+		ok.assert_async().await;
+		forbidden.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_label_endpoints() {
+		let mut server = Server::new_async().await;
+		let add = server
+			.mock("POST", "/api/v3/repos/owner/repo/issues/7/labels")
+			.match_body(Matcher::Json(serde_json::json!({ "labels": ["size/XS"] })))
+			.with_status(200)
+			.create_async()
+			.await;
+		let remove = server
+			.mock(
+				"DELETE",
+				"/api/v3/repos/owner/repo/issues/7/labels/size%2FXL",
+			)
+			.with_status(404)
+			.create_async()
+			.await;
+
+		let client = create_client(&server);
+		client
+			.add_labels(7, &["size/XS".to_string()])
+			.await
+			.unwrap();
+		client.remove_label(7, "size/XL").await.unwrap();
+		add.assert_async().await;
+		remove.assert_async().await;
+	}
+}
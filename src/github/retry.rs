@@ -0,0 +1,247 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::ApiConfig;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::hash::{BuildHasher, RandomState};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+/// How `GitHubClient` retries transient failures.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+	pub max_retries: u32,
+	/// Delay before the first retry, doubled for each further attempt
+	pub initial_backoff: Duration,
+	/// Longest delay, including waits requested by rate limit headers
+	pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_retries: 3,
+			initial_backoff: Duration::from_secs(1),
+			max_backoff: Duration::from_secs(60),
+		}
+	}
+}
+
+impl From<&ApiConfig> for RetryPolicy {
+	fn from(config: &ApiConfig) -> Self {
+		let defaults = Self::default();
+		Self {
+			max_retries: config.max_retries.unwrap_or(defaults.max_retries),
+			initial_backoff: config
+				.initial_backoff_ms
+				.map_or(defaults.initial_backoff, Duration::from_millis),
+			max_backoff: config
+				.max_backoff_secs
+				.map_or(defaults.max_backoff, Duration::from_secs),
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Exponential backoff with jitter: a random delay between half and all
+	/// of `initial_backoff * 2^attempt`, capped at `max_backoff`.
+	pub fn backoff(&self, attempt: u32) -> Duration {
+		let full = self
+			.initial_backoff
+			.saturating_mul(2u32.saturating_pow(attempt))
+			.min(self.max_backoff);
+		full / 2 + full.mul_f64(jitter() / 2.0)
+	}
+
+	/// Delay before retrying a response, or `None` when it should be
+	/// returned as is.
+	pub fn retry_delay(
+		&self,
+		status: StatusCode,
+		headers: &HeaderMap,
+		attempt: u32,
+	) -> Option<Duration> {
+		if attempt >= self.max_retries {
+			return None;
+		}
+
+		// Secondary rate limits answer 403 with `Retry-After`, primary ones
+		// with an exhausted `X-RateLimit-Remaining`
+		let rate_limited = status == StatusCode::TOO_MANY_REQUESTS
+			|| (status == StatusCode::FORBIDDEN
+				&& (headers.contains_key("retry-after")
+					|| header(headers, "x-ratelimit-remaining") == Some(0)));
+		if !status.is_server_error() && !rate_limited {
+			return None;
+		}
+
+		match requested_wait(headers, SystemTime::now()) {
+			Some(wait) if wait > self.max_backoff => {
+				warn!(
+					"GitHub API asked to wait {}s, longer than the {}s limit; not retrying",
+					wait.as_secs(),
+					self.max_backoff.as_secs()
+				);
+				None
+			}
+			Some(wait) => Some(wait),
+			None => Some(self.backoff(attempt)),
+		}
+	}
+}
+
+/// Wait requested by `Retry-After`, or until `X-RateLimit-Reset` once the
+/// quota is exhausted.
+fn requested_wait(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+	if let Some(seconds) = header(headers, "retry-after") {
+		return Some(Duration::from_secs(seconds));
+	}
+	if header(headers, "x-ratelimit-remaining") != Some(0) {
+		return None;
+	}
+	let reset = UNIX_EPOCH + Duration::from_secs(header(headers, "x-ratelimit-reset")?);
+	Some(reset.duration_since(now).unwrap_or_default())
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<u64> {
+	headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Log the remaining REST API quota reported by a response.
+pub fn log_quota(headers: &HeaderMap) {
+	let (Some(remaining), Some(limit)) = (
+		header(headers, "x-ratelimit-remaining"),
+		header(headers, "x-ratelimit-limit"),
+	) else {
+		return;
+	};
+	if remaining * 10 < limit {
+		warn!(
+			"GitHub API quota is low: {} of {} requests remaining",
+			remaining, limit
+		);
+	} else {
+		debug!(
+			"GitHub API quota: {} of {} requests remaining",
+			remaining, limit
+		);
+	}
+}
+
+/// Whether a transport error is worth retrying.
+pub fn is_transient(error: &reqwest::Error) -> bool {
+	error.is_connect() || error.is_timeout() || error.is_request()
+}
+
+/// A random fraction in `[0, 1)`, from the randomly keyed std hasher.
+fn jitter() -> f64 {
+	let bits = RandomState::new().hash_one(SystemTime::now());
+	(bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+		let mut map = HeaderMap::new();
+		for (name, value) in pairs {
+			map.insert(*name, HeaderValue::from_str(value).unwrap());
+		}
+		map
+	}
+
+	#[test]
+	fn test_backoff_grows_and_is_capped() {
+		let policy = RetryPolicy {
+			max_retries: 5,
+			initial_backoff: Duration::from_millis(100),
+			max_backoff: Duration::from_millis(300),
+		};
+		for _ in 0..20 {
+			let first = policy.backoff(0);
+			assert!((Duration::from_millis(50)..=Duration::from_millis(100)).contains(&first));
+			let second = policy.backoff(1);
+			assert!((Duration::from_millis(100)..=Duration::from_millis(200)).contains(&second));
+			assert!(policy.backoff(10) <= Duration::from_millis(300));
+		}
+	}
+
+	#[test]
+	fn test_requested_wait() {
+		let now = UNIX_EPOCH + Duration::from_secs(1_000);
+		let wait = requested_wait(&headers(&[("retry-after", "7")]), now);
+		assert_eq!(wait, Some(Duration::from_secs(7)));
+
+		let exhausted = headers(&[
+			("x-ratelimit-remaining", "0"),
+			("x-ratelimit-reset", "1030"),
+		]);
+		assert_eq!(
+			requested_wait(&exhausted, now),
+			Some(Duration::from_secs(30))
+		);
+
+		let remaining = headers(&[
+			("x-ratelimit-remaining", "10"),
+			("x-ratelimit-reset", "1030"),
+		]);
+		assert_eq!(requested_wait(&remaining, now), None);
+	}
+
+	#[test]
+	fn test_retry_delay() {
+		let policy = RetryPolicy {
+			max_retries: 2,
+			initial_backoff: Duration::ZERO,
+			max_backoff: Duration::from_secs(10),
+		};
+		let none = HeaderMap::new();
+
+		assert!(
+			policy
+				.retry_delay(StatusCode::BAD_GATEWAY, &none, 0)
+				.is_some()
+		);
+		assert!(
+			policy
+				.retry_delay(StatusCode::BAD_GATEWAY, &none, 2)
+				.is_none()
+		);
+		assert!(
+			policy
+				.retry_delay(StatusCode::NOT_FOUND, &none, 0)
+				.is_none()
+		);
+		assert!(
+			policy
+				.retry_delay(StatusCode::FORBIDDEN, &none, 0)
+				.is_none()
+		);
+
+		let secondary = headers(&[("retry-after", "3")]);
+		assert_eq!(
+			policy.retry_delay(StatusCode::FORBIDDEN, &secondary, 0),
+			Some(Duration::from_secs(3))
+		);
+		let too_long = headers(&[("retry-after", "600")]);
+		assert!(
+			policy
+				.retry_delay(StatusCode::TOO_MANY_REQUESTS, &too_long, 0)
+				.is_none()
+		);
+	}
+}
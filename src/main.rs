@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod actions;
 mod config;
 mod engine;
 mod error;
@@ -29,6 +30,15 @@ struct Args {
 	/// Path to config file
 	#[arg(long)]
 	config: Option<String>,
+
+	/// Print intended label changes instead of applying them
+	#[arg(long)]
+	dry_run: bool,
+
+	/// GitHub REST API root, e.g. `https://github.example.com/api/v3`
+	/// (default: `GITHUB_API_URL` or `https://api.github.com`)
+	#[arg(long)]
+	api_url: Option<String>,
 }
 
 fn print_annotation(level: &str, title: &str, message: &str) {
@@ -42,6 +52,33 @@ fn print_annotation(level: &str, title: &str, message: &str) {
 	);
 }
 
+fn print_violation(violation: &rules::Violation) {
+	// ::level file=path,line=n,title=title::message
+	let mut properties = String::new();
+	if let Some(location) = &violation.location {
+		properties.push_str(&format!("file={},", escape_annotation(&location.file)));
+		if let Some(line) = location.line {
+			properties.push_str(&format!("line={},", line));
+		}
+	}
+	properties.push_str(&format!(
+		"title={}",
+		escape_annotation(&format!("PR validation ({})", violation.rule_id))
+	));
+
+	let mut message = violation.message.clone();
+	if let Some(suggestion) = &violation.suggestion {
+		message.push_str(&format!("\nSuggestion: {}", suggestion));
+	}
+
+	println!(
+		"::{} {}::{}",
+		violation.severity.annotation_level(),
+		properties,
+		escape_annotation(&message)
+	);
+}
+
 fn escape_annotation(s: &str) -> String {
 	s.replace('%', "%25")
 		.replace('\r', "%0D")
@@ -50,6 +87,33 @@ fn escape_annotation(s: &str) -> String {
 		.replace(',', "%2C")
 }
 
+/// Append label changes to the job summary shown on the workflow run page.
+fn write_step_summary(changes: &[actions::LabelChange], dry_run: bool) {
+	let Ok(path) = std::env::var("GITHUB_STEP_SUMMARY") else {
+		return;
+	};
+	if changes.is_empty() {
+		return;
+	}
+
+	let mut summary = String::from("### pr-checker label changes\n\n");
+	if dry_run {
+		summary.push_str("_Dry run: nothing was changed._\n\n");
+	}
+	for change in changes {
+		summary.push_str(&format!("- {}\n", change));
+	}
+
+	let result = std::fs::OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(&path)
+		.and_then(|mut file| std::io::Write::write_all(&mut file, summary.as_bytes()));
+	if let Err(e) = result {
+		warn!("Failed to write step summary to {}: {}", path, e);
+	}
+}
+
 #[tokio::main]
 async fn main() {
 	// Initialize tracing
@@ -77,17 +141,40 @@ async fn main() {
 		.or_else(|| std::env::var("INPUT_CONFIG").ok())
 		.unwrap_or_else(|| ".github/pr-checker.yml".to_string());
 
-	let exit_code = match run(config_path.as_str()).await {
-		Ok(violations) => {
-			if violations.is_empty() {
-				info!("All PR checks passed!");
+	let dry_run = args.dry_run
+		|| std::env::var("INPUT_DRY_RUN").is_ok_and(|v| v.eq_ignore_ascii_case("true"));
+
+	let exit_code = match run(config_path.as_str(), dry_run, args.api_url).await {
+		Ok((report, mode)) => {
+			write_step_summary(&report.label_changes, dry_run);
+			let violations = report.violations;
+			let errors = violations
+				.iter()
+				.filter(|v| v.severity == rules::Severity::Error)
+				.count();
+			let warnings = violations
+				.iter()
+				.filter(|v| v.severity == rules::Severity::Warning)
+				.count();
+			for violation in &violations {
+				print_violation(violation);
+			}
+
+			if errors > 0 && mode == config::Mode::Advisory {
+				warn!(
+					"Found {} error(s) and {} warning(s), not failing in advisory mode",
+					errors, warnings
+				);
 				0
-			} else {
-				error!("Found {} violation(s)", violations.len());
-				for violation in &violations {
-					print_annotation("error", "PR validation failed", &violation.message);
-				}
+			} else if errors > 0 {
+				error!("Found {} error(s) and {} warning(s)", errors, warnings);
 				1
+			} else if warnings > 0 {
+				warn!("PR checks passed with {} warning(s)", warnings);
+				0
+			} else {
+				info!("All PR checks passed!");
+				0
 			}
 		}
 		Err(e) => match e {
@@ -117,7 +204,11 @@ async fn main() {
 	std::process::exit(exit_code);
 }
 
-async fn run(config_path: &str) -> error::Result<Vec<rules::Violation>> {
+async fn run(
+	config_path: &str,
+	dry_run: bool,
+	api_url: Option<String>,
+) -> error::Result<(engine::Report, config::Mode)> {
 	info!("Starting PR checker...");
 	info!("Config path: {}", config_path);
 
@@ -140,16 +231,25 @@ async fn run(config_path: &str) -> error::Result<Vec<rules::Violation>> {
 	};
 
 	// Initialize GitHub client from environment
-	let client = github::GitHubClient::from_env()?;
-	info!("GitHub client initialized");
+	let api = config.api.clone().unwrap_or_default();
+	let mut client =
+		github::GitHubClient::from_env()?.with_retry_policy(github::RetryPolicy::from(&api));
+	if let Some(max_items) = api.max_items {
+		client = client.with_max_items(max_items);
+	}
+	if let Some(url) = api_url {
+		client = client.with_api_url(url);
+	}
+	info!("GitHub client initialized for {}", client.api_url());
 
 	// Parse PR number from event
 	let pr_number = github::GitHubClient::parse_pr_number_from_event()?;
 	info!("PR number: {}", pr_number);
 
 	// Create engine and run checks
-	let engine = engine::Engine::new(client, config);
-	let violations = engine.run(pr_number).await?;
+	let mode = config.mode;
+	let engine = engine::Engine::new(client, config).dry_run(dry_run);
+	let report = engine.run(pr_number).await?;
 
-	Ok(violations)
+	Ok((report, mode))
 }
@@ -0,0 +1,198 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{AlignmentRule, Config, LabelRule};
+use crate::github::PrContext;
+use crate::rules::conventional::title_type;
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, format_labels, has_label};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// Ensures the title type (e.g. `feat`) is backed by its configured label.
+pub struct AlignmentCheck {
+	labels: LabelRule,
+	type_labels: BTreeMap<String, Vec<String>>,
+}
+
+impl AlignmentCheck {
+	pub fn new(labels: LabelRule, type_labels: BTreeMap<String, Vec<String>>) -> Self {
+		Self {
+			labels,
+			type_labels,
+		}
+	}
+}
+
+/// Settings used when `labels` is configured without an `alignment` section.
+static DEFAULT_ALIGNMENT: AlignmentRule = AlignmentRule {
+	severity: None,
+	when: None,
+};
+
+impl ConfiguredRule for AlignmentCheck {
+	const ID: &'static str = "alignment";
+	type Config = AlignmentRule;
+
+	/// Registered alongside `labels`, whose required labels it checks.
+	fn section(config: &Config) -> Option<&AlignmentRule> {
+		config
+			.labels
+			.as_ref()
+			.map(|_| config.alignment.as_ref().unwrap_or(&DEFAULT_ALIGNMENT))
+	}
+
+	fn from_config(_: &AlignmentRule, config: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(
+			config.labels.clone().unwrap_or_default(),
+			config.type_labels(),
+		))
+	}
+}
+
+#[async_trait]
+impl Rule for AlignmentCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"title type must match its type_labels label"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		let pr = &ctx.pr;
+		let mut violations = Vec::new();
+
+		// Only check if required labels are configured and non-empty
+		let Some(required) = &self.labels.required else {
+			return Ok(violations);
+		};
+		if required.is_empty() {
+			return Ok(violations);
+		}
+
+		let Some(kind) = title_type(&pr.title) else {
+			return Ok(violations);
+		};
+		let Some(expected) = self.type_labels.get(&kind) else {
+			return Ok(violations);
+		};
+
+		// Check if any of the title type's labels is in the required list
+		if expected.iter().any(|l| required.contains(l))
+			&& !expected.iter().any(|l| has_label(pr, l))
+		{
+			let wanted = if expected.len() == 1 {
+				format!("label '{}'", expected[0])
+			} else {
+				format!("one of labels [{}]", expected.join(", "))
+			};
+			violations.push(
+				Violation::error(
+					"alignment/type-label",
+					format!(
+						"Title type '{}' requires {} (type_labels: {} -> {}), current labels: [{}], title: '{}'",
+						kind,
+						wanted,
+						kind,
+						expected.join(", "),
+						format_labels(pr),
+						pr.title
+					),
+				)
+				.with_suggestion(format!(
+					"Add the '{}' label or change the title type",
+					expected[0]
+				)),
+			);
+		}
+
+		Ok(violations)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::PullRequest;
+	use crate::github::pr::PullRequestLabel;
+
+	fn create_ctx(title: &str, labels: Vec<&str>) -> PrContext<'static> {
+		PrContext::new(PullRequest {
+			number: 1,
+			title: title.to_string(),
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		})
+	}
+
+	fn create_check(required: Vec<&str>, mapping: Vec<(&str, Vec<&str>)>) -> AlignmentCheck {
+		AlignmentCheck::new(
+			LabelRule {
+				required: Some(required.into_iter().map(String::from).collect()),
+				..Default::default()
+			},
+			mapping
+				.into_iter()
+				.map(|(k, v)| (k.to_string(), v.into_iter().map(String::from).collect()))
+				.collect(),
+		)
+	}
+
+	#[tokio::test]
+	async fn test_missing_mapped_label() {
+		let check = create_check(vec!["bug"], vec![("fix", vec!["bug"])]);
+		let ctx = create_ctx("fix: crash on start", vec![]);
+
+		let violations = check.evaluate(&ctx).await.unwrap();
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.contains("type_labels: fix -> bug"));
+	}
+
+	#[tokio::test]
+	async fn test_one_to_many_any_label_satisfies() {
+		let check = create_check(vec!["kind/bug"], vec![("fix", vec!["kind/bug", "bug"])]);
+		let ctx = create_ctx("fix(api): crash on start", vec!["bug"]);
+
+		let violations = check.evaluate(&ctx).await.unwrap();
+		assert!(violations.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_many_to_one() {
+		let mapping = vec![
+			("feat", vec!["type/enhancement"]),
+			("perf", vec!["type/enhancement"]),
+		];
+		let check = create_check(vec!["type/enhancement"], mapping);
+		let ctx = create_ctx("perf: faster parsing", vec!["type/enhancement"]);
+
+		let violations = check.evaluate(&ctx).await.unwrap();
+		assert!(violations.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_unmapped_type() {
+		let check = create_check(vec!["bug"], vec![("fix", vec!["bug"])]);
+		let ctx = create_ctx("style: reformat", vec![]);
+
+		let violations = check.evaluate(&ctx).await.unwrap();
+		assert!(violations.is_empty());
+	}
+}
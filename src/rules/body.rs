@@ -0,0 +1,261 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{BodyRule, Config};
+use crate::github::{PrContext, PullRequest};
+use crate::rules::markdown::{checkboxes, headings, parse_heading};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation};
+use async_trait::async_trait;
+use regex::Regex;
+
+pub struct BodyCheck {
+	rule: BodyRule,
+}
+
+impl BodyCheck {
+	pub fn new(rule: BodyRule) -> Self {
+		Self { rule }
+	}
+}
+
+impl ConfiguredRule for BodyCheck {
+	const ID: &'static str = "body";
+	type Config = BodyRule;
+
+	fn section(config: &Config) -> Option<&BodyRule> {
+		config.body.as_ref()
+	}
+
+	fn from_config(rule: &BodyRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for BodyCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"PR description length, content and checklist"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(check_body(&ctx.pr, &self.rule))
+	}
+}
+
+pub fn check_body(pr: &PullRequest, rule: &BodyRule) -> RuleResult {
+	let mut violations = Vec::new();
+	let body = pr.body.as_deref().unwrap_or_default();
+	let length = body.trim().chars().count();
+
+	// Check min_length
+	if let Some(min_len) = rule.min_length
+		&& length < min_len
+	{
+		violations.push(
+			Violation::error(
+				"body/min-length",
+				format!(
+					"PR description is too short ({} chars), minimum required: {}",
+					length, min_len
+				),
+			)
+			.with_suggestion("Describe what the change does and why"),
+		);
+	}
+
+	// Check max_length
+	if let Some(max_len) = rule.max_length
+		&& length > max_len
+	{
+		violations.push(Violation::error(
+			"body/max-length",
+			format!(
+				"PR description is too long ({} chars), maximum allowed: {}",
+				length, max_len
+			),
+		));
+	}
+
+	// Check required patterns
+	for pattern in rule.required_patterns.iter().flatten() {
+		match Regex::new(pattern) {
+			Ok(re) => {
+				if !re.is_match(body) {
+					violations.push(Violation::error(
+						"body/pattern",
+						format!(
+							"PR description does not match required pattern: {}",
+							pattern
+						),
+					));
+				}
+			}
+			Err(e) => {
+				violations.push(Violation::error(
+					"body/pattern",
+					format!("Invalid regex pattern '{}': {}", pattern, e),
+				));
+			}
+		}
+	}
+
+	// Check required headings
+	let found = headings(body);
+	for required in rule.required_headings.iter().flatten() {
+		// "## Test plan" pins the level, "Test plan" accepts any level
+		let (level, text) = match parse_heading(required) {
+			Some((level, text)) => (Some(level), text),
+			None => (None, required.trim().to_string()),
+		};
+		let present = found.iter().any(|h| {
+			h.text.eq_ignore_ascii_case(&text) && level.is_none_or(|level| level == h.level)
+		});
+		if !present {
+			violations.push(
+				Violation::error(
+					"body/heading",
+					format!("PR description is missing required heading '{}'", required),
+				)
+				.with_suggestion(format!("Add a '{}' section to the description", required)),
+			);
+		}
+	}
+
+	// Check forbidden content
+	for forbidden in rule.forbidden.iter().flatten() {
+		if body.contains(forbidden.as_str()) {
+			violations.push(
+				Violation::error(
+					"body/forbidden",
+					format!("PR description contains forbidden text '{}'", forbidden),
+				)
+				.with_suggestion("Replace leftover template text with real content"),
+			);
+		}
+	}
+
+	// Check task list items
+	if rule.require_checked_boxes {
+		for item in checkboxes(body).iter().filter(|c| !c.checked) {
+			violations.push(Violation::error(
+				"body/checkbox",
+				format!("PR description has an unticked checkbox: '{}'", item.text),
+			));
+		}
+	}
+
+	violations
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_pr(body: Option<&str>) -> PullRequest {
+		PullRequest {
+			number: 1,
+			title: "Test PR".to_string(),
+			body: body.map(String::from),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_missing_body_too_short() {
+		let rule = BodyRule {
+			min_length: Some(20),
+			..Default::default()
+		};
+
+		let violations = check_body(&create_pr(None), &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "body/min-length");
+	}
+
+	#[test]
+	fn test_max_length() {
+		let rule = BodyRule {
+			max_length: Some(10),
+			..Default::default()
+		};
+
+		let violations = check_body(&create_pr(Some(&"a".repeat(20))), &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "body/max-length");
+	}
+
+	#[test]
+	fn test_required_patterns() {
+		let rule = BodyRule {
+			required_patterns: Some(vec!["(?i)closes #\\d+".to_string()]),
+			..Default::default()
+		};
+
+		assert!(check_body(&create_pr(Some("Closes #12")), &rule).is_empty());
+		let violations = check_body(&create_pr(Some("No issue")), &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "body/pattern");
+	}
+
+	#[test]
+	fn test_required_headings() {
+		let rule = BodyRule {
+			required_headings: Some(vec!["## Test plan".to_string(), "Summary".to_string()]),
+			..Default::default()
+		};
+
+		let pr = create_pr(Some("# Summary\nstuff\n### Test plan\nran it"));
+		let violations = check_body(&pr, &rule);
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.contains("## Test plan"));
+
+		let pr = create_pr(Some("### Summary\nstuff\n## test plan\nran it"));
+		assert!(check_body(&pr, &rule).is_empty());
+	}
+
+	#[test]
+	fn test_forbidden_content() {
+		let rule = BodyRule {
+			forbidden: Some(vec!["<describe your change>".to_string()]),
+			..Default::default()
+		};
+
+		let violations = check_body(
+			&create_pr(Some("## Summary\n<describe your change>")),
+			&rule,
+		);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "body/forbidden");
+	}
+
+	#[test]
+	fn test_unticked_checkboxes() {
+		let rule = BodyRule {
+			require_checked_boxes: true,
+			..Default::default()
+		};
+
+		let pr = create_pr(Some("- [x] Tests\n- [ ] Docs\n- [ ] Changelog"));
+		let violations = check_body(&pr, &rule);
+		assert_eq!(violations.len(), 2);
+		assert!(violations[0].message.contains("Docs"));
+
+		let pr = create_pr(Some("- [x] Tests\n<!--\n- [ ] Docs\n-->"));
+		assert!(check_body(&pr, &rule).is_empty());
+	}
+}
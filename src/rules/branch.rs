@@ -0,0 +1,216 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{BranchRule, Config};
+use crate::github::{PrContext, PullRequest};
+use crate::rules::conventional::title_type;
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, glob};
+use async_trait::async_trait;
+use globset::GlobSet;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+pub struct BranchCheck {
+	rule: BranchRule,
+	allowed_bases: Option<GlobSet>,
+	/// Branch prefixes compared against the title type
+	known_types: BTreeSet<String>,
+}
+
+impl BranchCheck {
+	pub fn new(rule: &BranchRule, known_types: BTreeSet<String>) -> crate::error::Result<Self> {
+		Ok(Self {
+			allowed_bases: rule.allowed_bases.as_deref().map(glob::set).transpose()?,
+			rule: rule.clone(),
+			known_types,
+		})
+	}
+
+	fn check(&self, pr: &PullRequest) -> RuleResult {
+		let mut violations = Vec::new();
+		let head = &pr.head.name;
+		let base = &pr.base.name;
+
+		// Check head branch pattern
+		if let Some(pattern) = &self.rule.pattern {
+			match Regex::new(pattern) {
+				Ok(re) => {
+					if !re.is_match(head) {
+						violations.push(Violation::error(
+							"branch/pattern",
+							format!(
+								"Branch '{}' does not match required pattern: {}",
+								head, pattern
+							),
+						));
+					}
+				}
+				Err(e) => {
+					violations.push(Violation::error(
+						"branch/pattern",
+						format!("Invalid regex pattern '{}': {}", pattern, e),
+					));
+				}
+			}
+		}
+
+		// Check base branch
+		if let Some(allowed) = &self.allowed_bases
+			&& !allowed.is_match(base)
+		{
+			violations.push(
+				Violation::error(
+					"branch/base",
+					format!(
+						"PR targets '{}', allowed base branches: {}",
+						base,
+						self.rule
+							.allowed_bases
+							.as_deref()
+							.unwrap_or_default()
+							.join(", ")
+					),
+				)
+				.with_suggestion("Change the base branch of the PR"),
+			);
+		}
+
+		// Check that `feat/...` branches carry `feat:` titles. Other first
+		// segments, like `dependabot/` or a user name, are not type prefixes
+		if self.rule.match_title_type
+			&& let Some((prefix, _)) = head.split_once('/')
+			&& self.known_types.contains(&prefix.to_lowercase())
+			&& let Some(kind) = title_type(&pr.title)
+			&& !prefix.eq_ignore_ascii_case(&kind)
+		{
+			violations.push(Violation::error(
+				"branch/title-type",
+				format!(
+					"Branch '{}' has type prefix '{}' but the title type is '{}'",
+					head, prefix, kind
+				),
+			));
+		}
+
+		violations
+	}
+}
+
+impl ConfiguredRule for BranchCheck {
+	const ID: &'static str = "branch";
+	type Config = BranchRule;
+
+	fn section(config: &Config) -> Option<&BranchRule> {
+		config.branch.as_ref()
+	}
+
+	fn from_config(rule: &BranchRule, config: &Config) -> crate::error::Result<Self> {
+		Self::new(rule, config.known_types())
+	}
+}
+
+#[async_trait]
+impl Rule for BranchCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"head and base branch names"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(self.check(&ctx.pr))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::PullRequestRef;
+
+	fn create_pr(title: &str, head: &str, base: &str) -> PullRequest {
+		PullRequest {
+			title: title.to_string(),
+			head: PullRequestRef {
+				name: head.to_string(),
+			},
+			base: PullRequestRef {
+				name: base.to_string(),
+			},
+			..Default::default()
+		}
+	}
+
+	fn create_check(yaml: &str) -> BranchCheck {
+		let config: crate::config::Config = serde_yaml::from_str("{}").unwrap();
+		BranchCheck::new(&serde_yaml::from_str(yaml).unwrap(), config.known_types()).unwrap()
+	}
+
+	#[test]
+	fn test_pattern() {
+		let check = create_check("pattern: \"^(feat|fix)/[A-Z]+-\\\\d+-\"\n");
+		let pr = create_pr("feat: add", "feat/PROJ-12-add-login", "main");
+		assert!(check.check(&pr).is_empty());
+
+		let violations = check.check(&create_pr("feat: add", "add-login", "main"));
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "branch/pattern");
+	}
+
+	#[test]
+	fn test_allowed_bases() {
+		let check = create_check("allowed_bases: [main, \"release/*\"]\n");
+		assert!(
+			check
+				.check(&create_pr("fix: x", "fix/a", "release/1.2"))
+				.is_empty()
+		);
+
+		let violations = check.check(&create_pr("fix: x", "fix/a", "develop"));
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "branch/base");
+	}
+
+	#[test]
+	fn test_match_title_type() {
+		let check = create_check("match_title_type: true\n");
+		assert!(
+			check
+				.check(&create_pr("fix(api): x", "fix/crash", "main"))
+				.is_empty()
+		);
+		assert!(
+			check
+				.check(&create_pr("fix: x", "hotfix-crash", "main"))
+				.is_empty()
+		);
+
+		let violations = check.check(&create_pr("feat: x", "fix/crash", "main"));
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "branch/title-type");
+	}
+
+	#[test]
+	fn test_match_title_type_ignores_other_prefixes() {
+		let check = create_check("match_title_type: true\n");
+		for (title, head) in [
+			("chore(deps): bump serde", "dependabot/cargo/serde-1.0"),
+			("fix: crash", "jane/fix-crash"),
+			("feat: x", "feature/x"),
+		] {
+			assert!(check.check(&create_pr(title, head, "main")).is_empty());
+		}
+	}
+}
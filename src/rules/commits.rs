@@ -0,0 +1,402 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{CommitRule, Config};
+use crate::github::{PrContext, PullRequest, PullRequestCommit};
+use crate::rules::conventional::check_conventional;
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation};
+use async_trait::async_trait;
+
+/// Subject prefixes `git commit --fixup/--squash` produce for autosquash.
+const AUTOSQUASH_PREFIXES: &[&str] = &["fixup!", "squash!", "amend!"];
+
+pub struct CommitCheck {
+	rule: CommitRule,
+}
+
+impl CommitCheck {
+	pub fn new(rule: CommitRule) -> Self {
+		Self { rule }
+	}
+}
+
+impl ConfiguredRule for CommitCheck {
+	const ID: &'static str = "commits";
+	type Config = CommitRule;
+
+	fn section(config: &Config) -> Option<&CommitRule> {
+		config.commits.as_ref()
+	}
+
+	fn from_config(rule: &CommitRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for CommitCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"commit message format and commit count"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(check_commits(ctx.commits().await?, &self.rule, &ctx.pr))
+	}
+}
+
+pub fn check_commits(
+	commits: &[PullRequestCommit],
+	rule: &CommitRule,
+	pr: &PullRequest,
+) -> RuleResult {
+	let mut violations = Vec::new();
+
+	// The fetched list stops at GitHub's 250 commit limit or `api.max_items`,
+	// so count from the PR itself
+	let count = pr.commits.max(commits.len() as u64);
+	if let Some(max) = rule.max_commits
+		&& count > max as u64
+	{
+		violations.push(
+			Violation::error(
+				"commits/max-commits",
+				format!("PR has {} commits, maximum allowed: {}", count, max),
+			)
+			.with_suggestion("Squash related commits together"),
+		);
+	}
+
+	for commit in commits {
+		if rule.require_signed {
+			violations.extend(check_signature(commit, rule, pr));
+		}
+
+		// Merge commits carry generated messages, so only the commits
+		// authored on the branch are linted
+		if commit.is_merge() {
+			continue;
+		}
+		violations.extend(check_message(commit, rule, pr).into_iter().map(|mut v| {
+			v.message = format!("Commit {}: {}", commit.short_sha(), v.message);
+			v
+		}));
+	}
+
+	violations
+}
+
+fn check_signature(
+	commit: &PullRequestCommit,
+	rule: &CommitRule,
+	pr: &PullRequest,
+) -> Option<Violation> {
+	let reason = match &commit.commit.verification {
+		Some(verification) if verification.verified => return None,
+		Some(verification) => verification.reason.as_str(),
+		None => "unknown",
+	};
+	if rule.allow_unsigned_base_merges && merges_base(commit, &pr.base.name) {
+		return None;
+	}
+
+	let problem = if reason == "unsigned" {
+		"is not signed".to_string()
+	} else {
+		format!("has a signature GitHub could not verify ({})", reason)
+	};
+	Some(
+		Violation::error(
+			"commits/unsigned",
+			format!("Commit {} {}", commit.short_sha(), problem),
+		)
+		.with_suggestion("Sign commits with `git commit -S` and register the key on GitHub"),
+	)
+}
+
+/// Whether `commit` merges the base branch into the PR, e.g.
+/// `Merge branch 'main' into feature`.
+fn merges_base(commit: &PullRequestCommit, base: &str) -> bool {
+	if !commit.is_merge() {
+		return false;
+	}
+	let subject = commit.subject();
+	let merged = subject
+		.strip_prefix("Merge branch '")
+		.or_else(|| subject.strip_prefix("Merge remote-tracking branch '"))
+		.and_then(|rest| rest.split_once('\''))
+		.map(|(branch, _)| branch);
+	merged.is_some_and(|branch| {
+		branch == base || branch.rsplit_once('/').is_some_and(|(_, b)| b == base)
+	})
+}
+
+fn check_message(commit: &PullRequestCommit, rule: &CommitRule, pr: &PullRequest) -> RuleResult {
+	let mut violations = Vec::new();
+	let subject = commit.subject();
+
+	if rule.forbid_autosquash
+		&& let Some(prefix) = AUTOSQUASH_PREFIXES.iter().find(|p| subject.starts_with(*p))
+	{
+		violations.push(
+			Violation::error(
+				"commits/autosquash",
+				format!("'{}' is a {} commit", subject, prefix.trim_end_matches('!')),
+			)
+			.with_suggestion("Run `git rebase -i --autosquash` before merging"),
+		);
+	}
+
+	if rule.forbid_wip && is_wip(subject) {
+		violations.push(Violation::error(
+			"commits/wip",
+			format!("'{}' is marked as work in progress", subject),
+		));
+	}
+
+	if let Some(max_len) = rule.max_subject_length
+		&& subject.chars().count() > max_len
+	{
+		violations.push(Violation::error(
+			"commits/subject-length",
+			format!(
+				"Subject '{}' is too long ({} chars), maximum allowed: {}",
+				subject,
+				subject.chars().count(),
+				max_len
+			),
+		));
+	}
+
+	if rule.require_blank_line
+		&& let Some(second) = commit.commit.message.lines().nth(1)
+		&& !second.trim().is_empty()
+	{
+		violations.push(
+			Violation::error(
+				"commits/blank-line",
+				"Subject must be followed by an empty line",
+			)
+			.with_suggestion("Separate the subject from the body with a blank line"),
+		);
+	}
+
+	if let Some(max_len) = rule.max_body_line_length
+		&& let Some((number, line)) = commit
+			.commit
+			.message
+			.lines()
+			.enumerate()
+			.skip(1)
+			.find(|(_, l)| l.chars().count() > max_len && l.trim().contains(char::is_whitespace))
+	{
+		violations.push(
+			Violation::error(
+				"commits/body-line-length",
+				format!(
+					"Body line {} is too long ({} chars), maximum allowed: {}",
+					number + 1,
+					line.chars().count(),
+					max_len
+				),
+			)
+			.with_suggestion(format!("Wrap the body at {} characters", max_len)),
+		);
+	}
+
+	if let Some(options) = &rule.conventional {
+		violations.extend(check_conventional("commits", subject, options, pr));
+	}
+
+	violations
+}
+
+/// `WIP`, `wip: ...` or `[WIP] ...`, but not words like `wipe`.
+fn is_wip(subject: &str) -> bool {
+	let subject = subject.trim_start().to_lowercase();
+	if subject.starts_with("[wip]") {
+		return true;
+	}
+	subject
+		.strip_prefix("wip")
+		.is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::{CommitDetails, CommitVerification, PullRequestRef};
+
+	fn create_commit(sha: &str, message: &str) -> PullRequestCommit {
+		PullRequestCommit {
+			sha: sha.to_string(),
+			commit: CommitDetails {
+				message: message.to_string(),
+				..Default::default()
+			},
+			..Default::default()
+		}
+	}
+
+	fn check(yaml: &str, commits: &[PullRequestCommit]) -> Vec<String> {
+		let rule: CommitRule = serde_yaml::from_str(yaml).unwrap();
+		check_commits(commits, &rule, &PullRequest::default())
+			.into_iter()
+			.map(|v| v.rule_id)
+			.collect()
+	}
+
+	#[test]
+	fn test_conventional_names_sha() {
+		let rule: CommitRule =
+			serde_yaml::from_str("conventional:\n  allowed_types: [feat, fix]\n").unwrap();
+		let commits = [
+			create_commit("0123456789abcdef", "feat: add commits rule"),
+			create_commit("fedcba9876543210", "update docs"),
+		];
+
+		let violations = check_commits(&commits, &rule, &PullRequest::default());
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "commits/conventional");
+		assert!(violations[0].message.starts_with("Commit fedcba9:"));
+	}
+
+	#[test]
+	fn test_message_layout() {
+		let yaml = "max_subject_length: 20\nrequire_blank_line: true\nmax_body_line_length: 30\n";
+		let long_line = "this body line is definitely longer than thirty characters";
+
+		assert!(check(yaml, &[create_commit("a", "fix: short\n\nwrapped body")]).is_empty());
+		assert_eq!(
+			check(
+				yaml,
+				&[create_commit("a", "fix: a subject that is too long")]
+			),
+			vec!["commits/subject-length"]
+		);
+		assert_eq!(
+			check(yaml, &[create_commit("a", "fix: short\nno blank line")]),
+			vec!["commits/blank-line"]
+		);
+		assert_eq!(
+			check(
+				yaml,
+				&[create_commit("a", &format!("fix: short\n\n{}", long_line))]
+			),
+			vec!["commits/body-line-length"]
+		);
+		let url = "https://example.com/a/very/long/link/that/cannot/be/wrapped";
+		assert!(
+			check(
+				yaml,
+				&[create_commit("a", &format!("fix: short\n\n{}", url))]
+			)
+			.is_empty()
+		);
+	}
+
+	#[test]
+	fn test_forbidden_subjects() {
+		let yaml = "forbid_autosquash: true\nforbid_wip: true\n";
+		let commits = [
+			create_commit("a", "fixup! feat: add rule"),
+			create_commit("b", "WIP: still going"),
+			create_commit("c", "[wip] more"),
+			create_commit("d", "fix: wipe stale cache"),
+		];
+		assert_eq!(
+			check(yaml, &commits),
+			vec!["commits/autosquash", "commits/wip", "commits/wip"]
+		);
+	}
+
+	#[test]
+	fn test_require_signed() {
+		let signed = |verified: bool, reason: &str| CommitVerification {
+			verified,
+			reason: reason.to_string(),
+		};
+		let mut good = create_commit("0123456789", "feat: add");
+		good.commit.verification = Some(signed(true, "valid"));
+		let mut unsigned = create_commit("abcdef0123", "fix: crash");
+		unsigned.commit.verification = Some(signed(false, "unsigned"));
+		let mut bad = create_commit("fedcba9876", "fix: typo");
+		bad.commit.verification = Some(signed(false, "bad_email"));
+
+		let rule: CommitRule = serde_yaml::from_str("require_signed: true").unwrap();
+		let violations = check_commits(&[good, unsigned, bad], &rule, &PullRequest::default());
+		assert_eq!(violations.len(), 2);
+		assert_eq!(violations[0].rule_id, "commits/unsigned");
+		assert!(violations[0].message.contains("abcdef0 is not signed"));
+		assert!(violations[1].message.contains("bad_email"));
+	}
+
+	#[test]
+	fn test_unsigned_base_merges() {
+		let pr = PullRequest {
+			base: PullRequestRef {
+				name: "main".to_string(),
+			},
+			..Default::default()
+		};
+		let merge = |message: &str| PullRequestCommit {
+			parents: vec![serde::de::IgnoredAny; 2],
+			..create_commit("m", message)
+		};
+		let commits = [
+			merge("Merge branch 'main' into feature"),
+			merge("Merge remote-tracking branch 'origin/main' into feature"),
+			merge("Merge branch 'other' into feature"),
+		];
+
+		let rule: CommitRule =
+			serde_yaml::from_str("require_signed: true\nallow_unsigned_base_merges: true").unwrap();
+		let violations = check_commits(&commits, &rule, &pr);
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.contains("unknown"));
+
+		let rule: CommitRule = serde_yaml::from_str("require_signed: true").unwrap();
+		assert_eq!(check_commits(&commits, &rule, &pr).len(), 3);
+	}
+
+	#[test]
+	fn test_max_commits_and_merges() {
+		let merge = PullRequestCommit {
+			parents: vec![serde::de::IgnoredAny; 2],
+			..create_commit("m", "Merge branch 'main' into feature")
+		};
+		let commits = [create_commit("a", "feat: add"), merge];
+
+		assert!(check("conventional: {}\n", &commits).is_empty());
+		assert_eq!(
+			check("max_commits: 1\n", &commits),
+			vec!["commits/max-commits"]
+		);
+	}
+
+	#[test]
+	fn test_max_commits_counts_unlisted_commits() {
+		let pr = PullRequest {
+			commits: 400,
+			..Default::default()
+		};
+		let rule: CommitRule = serde_yaml::from_str("max_commits: 300").unwrap();
+		let violations = check_commits(&[create_commit("a", "feat: add")], &rule, &pr);
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.starts_with("PR has 400 commits"));
+	}
+}
@@ -0,0 +1,166 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::When;
+use crate::github::PrContext;
+use crate::rules::{glob, has_label};
+use globset::GlobSet;
+
+/// A compiled `when:` block. Every predicate that is set must hold; within a
+/// list, any entry may match.
+pub struct Condition {
+	when: When,
+	base_branches: Option<GlobSet>,
+	head_branches: Option<GlobSet>,
+	paths: Option<GlobSet>,
+}
+
+impl Condition {
+	pub fn new(when: &When) -> crate::error::Result<Self> {
+		let compile = |globs: &Option<Vec<String>>| globs.as_deref().map(glob::set).transpose();
+		Ok(Self {
+			base_branches: compile(&when.base_branches)?,
+			head_branches: compile(&when.head_branches)?,
+			paths: compile(&when.paths)?,
+			when: when.clone(),
+		})
+	}
+
+	/// Whether the PR satisfies every predicate. Changed files are only
+	/// fetched when a `paths` predicate is set.
+	pub async fn matches(&self, ctx: &PrContext<'_>) -> crate::error::Result<bool> {
+		let pr = &ctx.pr;
+		let when = &self.when;
+
+		if let Some(authors) = &when.authors
+			&& !authors.contains(&pr.user.login)
+		{
+			return Ok(false);
+		}
+		if let Some(authors) = &when.exclude_authors
+			&& authors.contains(&pr.user.login)
+		{
+			return Ok(false);
+		}
+		if let Some(base) = &self.base_branches
+			&& !base.is_match(&pr.base.name)
+		{
+			return Ok(false);
+		}
+		if let Some(head) = &self.head_branches
+			&& !head.is_match(&pr.head.name)
+		{
+			return Ok(false);
+		}
+		if let Some(labels) = &when.labels
+			&& !labels.iter().any(|l| has_label(pr, l))
+		{
+			return Ok(false);
+		}
+		if let Some(labels) = &when.exclude_labels
+			&& labels.iter().any(|l| has_label(pr, l))
+		{
+			return Ok(false);
+		}
+		if let Some(draft) = when.draft
+			&& pr.draft != draft
+		{
+			return Ok(false);
+		}
+		if let Some(paths) = &self.paths {
+			let files = ctx.files().await?;
+			if !files.iter().any(|f| paths.is_match(&f.filename)) {
+				return Ok(false);
+			}
+		}
+
+		Ok(true)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::{PullRequestLabel, PullRequestRef, PullRequestUser};
+	use crate::github::{PullRequest, PullRequestFile};
+
+	fn create_ctx() -> PrContext<'static> {
+		let pr = PullRequest {
+			user: PullRequestUser {
+				login: "dependabot[bot]".to_string(),
+				kind: "Bot".to_string(),
+			},
+			base: PullRequestRef {
+				name: "main".to_string(),
+			},
+			head: PullRequestRef {
+				name: "dependabot/cargo/serde-1.0".to_string(),
+			},
+			labels: vec![PullRequestLabel {
+				name: "dependencies".to_string(),
+			}],
+			..Default::default()
+		};
+		PrContext::new(pr).with_files(vec![PullRequestFile {
+			filename: "api/v1/users.rs".to_string(),
+			..Default::default()
+		}])
+	}
+
+	async fn matches(yaml: &str) -> bool {
+		let when: When = serde_yaml::from_str(yaml).unwrap();
+		Condition::new(&when)
+			.unwrap()
+			.matches(&create_ctx())
+			.await
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_empty_when_matches() {
+		assert!(matches("{}").await);
+	}
+
+	#[tokio::test]
+	async fn test_authors() {
+		assert!(matches("authors: [\"dependabot[bot]\"]").await);
+		assert!(!matches("exclude_authors: [\"dependabot[bot]\"]").await);
+	}
+
+	#[tokio::test]
+	async fn test_branches() {
+		assert!(matches("base_branches: [main]").await);
+		assert!(!matches("base_branches: [\"release/*\"]").await);
+		assert!(matches("head_branches: [\"dependabot/**\"]").await);
+	}
+
+	#[tokio::test]
+	async fn test_labels_and_draft() {
+		assert!(matches("labels: [dependencies, security]").await);
+		assert!(!matches("exclude_labels: [dependencies]").await);
+		assert!(matches("draft: false").await);
+		assert!(!matches("draft: true").await);
+	}
+
+	#[tokio::test]
+	async fn test_paths() {
+		assert!(matches("paths: [\"api/**\"]").await);
+		assert!(!matches("paths: [\"web/**\"]").await);
+	}
+
+	#[tokio::test]
+	async fn test_all_predicates_must_hold() {
+		assert!(!matches("base_branches: [main]\ndraft: true").await);
+	}
+}
@@ -0,0 +1,330 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{ConventionalOptions, SubjectCase};
+use crate::github::PullRequest;
+use crate::rules::{RuleResult, Violation, has_label};
+
+/// A parsed Conventional Commits header: `type(scope)!: description`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+	pub kind: String,
+	pub scopes: Vec<String>,
+	pub breaking: bool,
+	pub description: String,
+}
+
+/// Parse a PR title or commit subject, returning why it is not conventional
+/// on failure.
+pub fn parse(header: &str) -> Result<ConventionalCommit, String> {
+	let Some((prefix, description)) = header.split_once(':') else {
+		return Err("missing ':' after the type".to_string());
+	};
+
+	let (prefix, breaking) = match prefix.strip_suffix('!') {
+		Some(p) => (p, true),
+		None => (prefix, false),
+	};
+
+	let (kind, scopes) = match prefix.split_once('(') {
+		Some((kind, rest)) => {
+			let Some(scope) = rest.strip_suffix(')') else {
+				return Err("unterminated '(' in scope".to_string());
+			};
+			let scopes: Vec<String> = scope.split(',').map(|s| s.trim().to_string()).collect();
+			if scopes.iter().any(|s| s.is_empty()) {
+				return Err("empty scope".to_string());
+			}
+			(kind, scopes)
+		}
+		None => (prefix, Vec::new()),
+	};
+
+	if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+		return Err(format!("invalid type '{}'", kind));
+	}
+
+	let description = description.trim();
+	if description.is_empty() {
+		return Err("missing description after ':'".to_string());
+	}
+
+	Ok(ConventionalCommit {
+		kind: kind.to_string(),
+		scopes,
+		breaking,
+		description: description.to_string(),
+	})
+}
+
+/// Lowercased Conventional Commits type of a title, e.g. `feat`.
+pub fn title_type(title: &str) -> Option<String> {
+	parse(title).ok().map(|c| c.kind.to_lowercase())
+}
+
+/// Apply the Conventional Commits options to `header`, reporting violations
+/// under `prefix` (e.g. `title/scope`).
+pub fn check_conventional(
+	prefix: &str,
+	header: &str,
+	options: &ConventionalOptions,
+	pr: &PullRequest,
+) -> RuleResult {
+	let mut violations = Vec::new();
+
+	let commit = match parse(header) {
+		Ok(commit) => commit,
+		Err(reason) => {
+			violations.push(
+				Violation::error(
+					format!("{}/conventional", prefix),
+					format!(
+						"'{}' is not a Conventional Commits header: {}",
+						header, reason
+					),
+				)
+				.with_suggestion("Use the form 'type(scope): description'"),
+			);
+			return violations;
+		}
+	};
+
+	if let Some(allowed) = &options.allowed_types
+		&& !allowed.iter().any(|t| t.eq_ignore_ascii_case(&commit.kind))
+	{
+		violations.push(Violation::error(
+			format!("{}/type", prefix),
+			format!(
+				"Type '{}' in '{}' is not allowed, expected one of: {}",
+				commit.kind,
+				header,
+				allowed.join(", ")
+			),
+		));
+	}
+
+	if let Some(allowed) = &options.allowed_scopes {
+		// Case-insensitive like `allowed_types`
+		for scope in commit
+			.scopes
+			.iter()
+			.filter(|s| !allowed.iter().any(|a| a.eq_ignore_ascii_case(s)))
+		{
+			violations.push(Violation::error(
+				format!("{}/scope", prefix),
+				format!(
+					"Scope '{}' in '{}' is not allowed, expected one of: {}",
+					scope,
+					header,
+					allowed.join(", ")
+				),
+			));
+		}
+	}
+
+	if options.require_scope && commit.scopes.is_empty() {
+		violations.push(
+			Violation::error(
+				format!("{}/require-scope", prefix),
+				format!("'{}' must declare a scope", header),
+			)
+			.with_suggestion(format!("Use the form '{}(scope): ...'", commit.kind)),
+		);
+	}
+
+	if let Some(label) = &options.disallow_breaking_without_label
+		&& commit.breaking
+		&& !has_label(pr, label)
+	{
+		violations.push(
+			Violation::error(
+				format!("{}/breaking", prefix),
+				format!(
+					"Breaking change marker '!' in '{}' requires the '{}' label",
+					header, label
+				),
+			)
+			.with_suggestion(format!("Add the '{}' label or drop the '!'", label)),
+		);
+	}
+
+	if let Some(case) = options.subject_case
+		&& let Some(first) = commit.description.chars().next()
+	{
+		let ok = match case {
+			SubjectCase::Lower => !first.is_uppercase(),
+			SubjectCase::Sentence => !first.is_lowercase(),
+		};
+		if !ok {
+			violations.push(Violation::error(
+				format!("{}/subject-case", prefix),
+				format!(
+					"Description '{}' must start with a {} letter",
+					commit.description,
+					match case {
+						SubjectCase::Lower => "lowercase",
+						SubjectCase::Sentence => "uppercase",
+					}
+				),
+			));
+		}
+	}
+
+	if options.no_trailing_period && commit.description.ends_with('.') {
+		violations.push(
+			Violation::error(
+				format!("{}/trailing-period", prefix),
+				format!(
+					"Description '{}' must not end with a period",
+					commit.description
+				),
+			)
+			.with_suggestion("Remove the trailing '.'"),
+		);
+	}
+
+	violations
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::PullRequestLabel;
+
+	fn create_pr(labels: Vec<&str>) -> PullRequest {
+		PullRequest {
+			number: 1,
+			title: "Test PR".to_string(),
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_parse_full_header() {
+		let commit = parse("feat(api,cli)!: drop legacy flags").unwrap();
+		assert_eq!(commit.kind, "feat");
+		assert_eq!(commit.scopes, vec!["api", "cli"]);
+		assert!(commit.breaking);
+		assert_eq!(commit.description, "drop legacy flags");
+	}
+
+	#[test]
+	fn test_parse_without_scope() {
+		let commit = parse("fix: handle empty body").unwrap();
+		assert_eq!(commit.kind, "fix");
+		assert!(commit.scopes.is_empty());
+		assert!(!commit.breaking);
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		assert!(parse("Bump serde from 1.0 to 1.1").is_err());
+		assert!(parse("feat(api: missing paren").is_err());
+		assert!(parse("feat():").is_err());
+		assert!(parse("feat: ").is_err());
+	}
+
+	#[test]
+	fn test_title_type() {
+		assert_eq!(
+			title_type("Feat(api-server): add"),
+			Some("feat".to_string())
+		);
+		assert_eq!(title_type("no type here"), None);
+	}
+
+	#[test]
+	fn test_allowed_types_and_scopes() {
+		let options = ConventionalOptions {
+			allowed_types: Some(vec!["feat".to_string(), "fix".to_string()]),
+			allowed_scopes: Some(vec!["api".to_string()]),
+			..Default::default()
+		};
+		let pr = create_pr(vec![]);
+
+		let violations = check_conventional("title", "docs(web): update", &options, &pr);
+		let ids: Vec<&str> = violations.iter().map(|v| v.rule_id.as_str()).collect();
+		assert_eq!(ids, vec!["title/type", "title/scope"]);
+
+		assert!(check_conventional("title", "Fix(API): crash", &options, &pr).is_empty());
+	}
+
+	#[test]
+	fn test_require_scope() {
+		let options = ConventionalOptions {
+			require_scope: true,
+			..Default::default()
+		};
+		let pr = create_pr(vec![]);
+
+		let violations = check_conventional("title", "feat: add", &options, &pr);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "title/require-scope");
+	}
+
+	#[test]
+	fn test_breaking_requires_label() {
+		let options = ConventionalOptions {
+			disallow_breaking_without_label: Some("breaking-change".to_string()),
+			..Default::default()
+		};
+
+		let violations =
+			check_conventional("title", "feat!: drop v1", &options, &create_pr(vec![]));
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "title/breaking");
+
+		let pr = create_pr(vec!["breaking-change"]);
+		assert!(check_conventional("title", "feat!: drop v1", &options, &pr).is_empty());
+	}
+
+	#[test]
+	fn test_subject_case_and_period() {
+		let options = ConventionalOptions {
+			subject_case: Some(SubjectCase::Lower),
+			no_trailing_period: true,
+			..Default::default()
+		};
+		let pr = create_pr(vec![]);
+
+		let violations = check_conventional("title", "fix: Handle errors.", &options, &pr);
+		let ids: Vec<&str> = violations.iter().map(|v| v.rule_id.as_str()).collect();
+		assert_eq!(ids, vec!["title/subject-case", "title/trailing-period"]);
+
+		let options = ConventionalOptions {
+			subject_case: Some(SubjectCase::Sentence),
+			..Default::default()
+		};
+		assert!(check_conventional("title", "fix: Handle errors", &options, &pr).is_empty());
+	}
+
+	#[test]
+	fn test_not_conventional() {
+		let options = ConventionalOptions {
+			require_scope: true,
+			..Default::default()
+		};
+		let pr = create_pr(vec![]);
+
+		let violations = check_conventional("title", "update readme", &options, &pr);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "title/conventional");
+	}
+}
@@ -0,0 +1,264 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, DcoRule};
+use crate::github::{PrContext, PullRequestCommit};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation};
+use async_trait::async_trait;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A `Key: Name <email>` trailer line.
+static TRAILER: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"^([A-Za-z-]+):\s*(.*?)\s*<([^<>\s]+@[^<>\s]+)>\s*$").expect("valid trailer regex")
+});
+
+const NOREPLY_DOMAIN: &str = "@users.noreply.github.com";
+
+pub struct DcoCheck {
+	rule: DcoRule,
+}
+
+impl DcoCheck {
+	pub fn new(rule: DcoRule) -> Self {
+		Self { rule }
+	}
+}
+
+impl ConfiguredRule for DcoCheck {
+	const ID: &'static str = "dco";
+	type Config = DcoRule;
+
+	fn section(config: &Config) -> Option<&DcoRule> {
+		config.dco.as_ref()
+	}
+
+	fn from_config(rule: &DcoRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for DcoCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"commits are signed off by their author"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(ctx
+			.commits()
+			.await?
+			.iter()
+			.flat_map(|commit| check_commit(commit, &self.rule))
+			.collect())
+	}
+}
+
+/// A trailer's name and email, e.g. `Signed-off-by: Jane <jane@example.com>`.
+#[derive(Debug, PartialEq, Eq)]
+struct Signature<'m> {
+	name: &'m str,
+	email: &'m str,
+}
+
+/// Lines of `message` whose trailer key equals `key`, ignoring case.
+fn trailer_lines<'m>(message: &'m str, key: &str) -> impl Iterator<Item = &'m str> {
+	message.lines().map(str::trim).filter(move |line| {
+		line.split_once(':')
+			.is_some_and(|(k, _)| k.trim().eq_ignore_ascii_case(key))
+	})
+}
+
+fn parse_signature(line: &str) -> Option<Signature<'_>> {
+	let captures = TRAILER.captures(line)?;
+	let name = captures.get(2)?.as_str();
+	if name.is_empty() {
+		return None;
+	}
+	Some(Signature {
+		name,
+		email: captures.get(3)?.as_str(),
+	})
+}
+
+fn is_bot(commit: &PullRequestCommit) -> bool {
+	commit
+		.author
+		.as_ref()
+		.is_some_and(|user| user.kind.eq_ignore_ascii_case("bot") || user.login.ends_with("[bot]"))
+		|| commit
+			.commit
+			.author
+			.email
+			.ends_with(&format!("[bot]{}", NOREPLY_DOMAIN))
+}
+
+fn check_commit(commit: &PullRequestCommit, rule: &DcoRule) -> RuleResult {
+	// Merge commits are generated and bots cannot certify anything
+	if commit.is_merge() || (rule.allow_bots && is_bot(commit)) {
+		return Vec::new();
+	}
+
+	let sha = commit.short_sha();
+	let author = &commit.commit.author;
+	let message = &commit.commit.message;
+	let mut violations = Vec::new();
+
+	let sign_offs: Vec<Signature> = trailer_lines(message, "Signed-off-by")
+		.filter_map(parse_signature)
+		.collect();
+	let matching = sign_offs.iter().find(|s| {
+		s.name == author.name.trim() && s.email.eq_ignore_ascii_case(author.email.trim())
+	});
+
+	match matching {
+		None if sign_offs.is_empty() => violations.push(
+			Violation::error(
+				"dco/missing",
+				format!("Commit {} has no Signed-off-by trailer", sha),
+			)
+			.with_suggestion(
+				"Sign off your commits with `git commit -s`, e.g. `git rebase --signoff`",
+			),
+		),
+		None => violations.push(
+			Violation::error(
+				"dco/mismatch",
+				format!(
+					"Commit {} is signed off by {}, not its author {} <{}>",
+					sha,
+					sign_offs
+						.iter()
+						.map(|s| format!("{} <{}>", s.name, s.email))
+						.collect::<Vec<_>>()
+						.join(", "),
+					author.name,
+					author.email
+				),
+			)
+			.with_suggestion("Sign off with the same name and email as the commit author"),
+		),
+		Some(sign_off) if !rule.allow_noreply && sign_off.email.ends_with(NOREPLY_DOMAIN) => {
+			violations.push(
+				Violation::error(
+					"dco/noreply",
+					format!(
+						"Commit {} is signed off with the GitHub noreply address {}",
+						sha, sign_off.email
+					),
+				)
+				.with_suggestion("Author and sign off the commit with a real email address"),
+			)
+		}
+		Some(_) => {}
+	}
+
+	if rule.check_co_authors {
+		for line in trailer_lines(message, "Co-authored-by") {
+			if parse_signature(line).is_none() {
+				violations.push(
+					Violation::error(
+						"dco/co-author",
+						format!("Commit {} has a malformed trailer '{}'", sha, line),
+					)
+					.with_suggestion("Use the form 'Co-authored-by: Name <email>'"),
+				);
+			}
+		}
+	}
+
+	violations
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::{CommitDetails, CommitIdentity, PullRequestUser};
+
+	fn create_commit(message: &str) -> PullRequestCommit {
+		PullRequestCommit {
+			sha: "0123456789abcdef".to_string(),
+			commit: CommitDetails {
+				message: message.to_string(),
+				author: CommitIdentity {
+					name: "Jane Doe".to_string(),
+					email: "jane@example.com".to_string(),
+				},
+				..Default::default()
+			},
+			..Default::default()
+		}
+	}
+
+	fn ids(yaml: &str, commit: &PullRequestCommit) -> Vec<String> {
+		let rule: DcoRule = serde_yaml::from_str(yaml).unwrap();
+		check_commit(commit, &rule)
+			.into_iter()
+			.map(|v| v.rule_id)
+			.collect()
+	}
+
+	#[test]
+	fn test_signed_off() {
+		let commit = create_commit("fix: crash\n\nSigned-off-by: Jane Doe <JANE@example.com>");
+		assert!(ids("{}", &commit).is_empty());
+	}
+
+	#[test]
+	fn test_missing_and_mismatch() {
+		assert_eq!(ids("{}", &create_commit("fix: crash")), vec!["dco/missing"]);
+
+		let commit = create_commit("fix: crash\n\nSigned-off-by: John Roe <john@example.com>");
+		let violations = check_commit(&commit, &DcoRule::default());
+		assert_eq!(violations[0].rule_id, "dco/mismatch");
+		assert!(violations[0].message.starts_with("Commit 0123456 "));
+	}
+
+	#[test]
+	fn test_noreply() {
+		let mut commit = create_commit(
+			"fix: crash\n\nSigned-off-by: Jane Doe <1+jane@users.noreply.github.com>",
+		);
+		commit.commit.author.email = "1+jane@users.noreply.github.com".to_string();
+		assert_eq!(ids("{}", &commit), vec!["dco/noreply"]);
+		assert!(ids("allow_noreply: true", &commit).is_empty());
+	}
+
+	#[test]
+	fn test_bots() {
+		let mut commit = create_commit("chore(deps): bump serde");
+		commit.author = Some(PullRequestUser {
+			login: "dependabot[bot]".to_string(),
+			kind: "Bot".to_string(),
+		});
+		assert_eq!(ids("{}", &commit), vec!["dco/missing"]);
+		assert!(ids("allow_bots: true", &commit).is_empty());
+	}
+
+	#[test]
+	fn test_co_authors() {
+		let commit = create_commit(
+			"fix: crash\n\nCo-authored-by: John Roe <john@example.com>\nco-authored-by: Max\nSigned-off-by: Jane Doe <jane@example.com>",
+		);
+		assert!(ids("{}", &commit).is_empty());
+		assert_eq!(
+			ids("check_co_authors: true", &commit),
+			vec!["dco/co-author"]
+		);
+	}
+}
@@ -0,0 +1,110 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::Exemptions;
+use crate::github::PullRequest;
+use crate::rules::has_label;
+
+/// Why the PR is exempt from all rules, if it is.
+pub fn exemption_reason(exemptions: &Exemptions, pr: &PullRequest) -> Option<String> {
+	let login = &pr.user.login;
+
+	if exemptions.authors.iter().flatten().any(|a| a == login) {
+		return Some(format!("author '{}' is exempt", login));
+	}
+
+	if exemptions.bots && pr.user.kind.eq_ignore_ascii_case("bot") {
+		return Some(format!("author '{}' is a bot", login));
+	}
+
+	if let Some(association) = exemptions
+		.associations
+		.iter()
+		.flatten()
+		.find(|a| a.eq_ignore_ascii_case(&pr.author_association))
+	{
+		return Some(format!(
+			"author '{}' has exempt association '{}'",
+			login, association
+		));
+	}
+
+	if let Some(label) = exemptions
+		.labels
+		.iter()
+		.flatten()
+		.find(|l| has_label(pr, l))
+	{
+		return Some(format!("bypass label '{}' is present", label));
+	}
+
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::{PullRequestLabel, PullRequestUser};
+
+	fn create_pr(login: &str, kind: &str, association: &str, labels: Vec<&str>) -> PullRequest {
+		PullRequest {
+			user: PullRequestUser {
+				login: login.to_string(),
+				kind: kind.to_string(),
+			},
+			author_association: association.to_string(),
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		}
+	}
+
+	fn exemptions(yaml: &str) -> Exemptions {
+		serde_yaml::from_str(yaml).unwrap()
+	}
+
+	#[test]
+	fn test_author() {
+		let pr = create_pr("renovate[bot]", "Bot", "NONE", vec![]);
+		let reason = exemption_reason(&exemptions("authors: [\"renovate[bot]\"]"), &pr);
+		assert!(reason.unwrap().contains("renovate[bot]"));
+	}
+
+	#[test]
+	fn test_bots() {
+		let pr = create_pr("dependabot[bot]", "Bot", "NONE", vec![]);
+		assert!(exemption_reason(&exemptions("bots: true"), &pr).is_some());
+		assert!(exemption_reason(&exemptions("bots: false"), &pr).is_none());
+	}
+
+	#[test]
+	fn test_association() {
+		let pr = create_pr("octocat", "User", "OWNER", vec![]);
+		assert!(exemption_reason(&exemptions("associations: [owner, member]"), &pr).is_some());
+
+		let pr = create_pr("newbie", "User", "FIRST_TIME_CONTRIBUTOR", vec![]);
+		assert!(exemption_reason(&exemptions("associations: [OWNER]"), &pr).is_none());
+	}
+
+	#[test]
+	fn test_bypass_label() {
+		let pr = create_pr("octocat", "User", "MEMBER", vec!["skip-checks"]);
+		let reason = exemption_reason(&exemptions("labels: [skip-checks]"), &pr);
+		assert_eq!(reason.unwrap(), "bypass label 'skip-checks' is present");
+	}
+}
@@ -0,0 +1,268 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, FileRule};
+use crate::github::{PrContext, PullRequestFile};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, glob};
+use async_trait::async_trait;
+use globset::{GlobMatcher, GlobSet};
+
+struct RequireTogether {
+	changed: GlobMatcher,
+	requires: GlobMatcher,
+}
+
+pub struct FileCheck {
+	forbidden: GlobSet,
+	forbidden_patterns: Vec<String>,
+	require_together: Vec<RequireTogether>,
+	max_files: Option<usize>,
+}
+
+impl FileCheck {
+	/// Compile the configured globs, failing on invalid patterns.
+	pub fn new(rule: &FileRule) -> crate::error::Result<Self> {
+		let forbidden_patterns = rule.forbidden.clone().unwrap_or_default();
+		let require_together = rule
+			.require_together
+			.iter()
+			.flatten()
+			.map(|pair| {
+				Ok(RequireTogether {
+					changed: glob::matcher(&pair.changed)?,
+					requires: glob::matcher(&pair.requires)?,
+				})
+			})
+			.collect::<crate::error::Result<Vec<_>>>()?;
+
+		Ok(Self {
+			forbidden: glob::set(&forbidden_patterns)?,
+			forbidden_patterns,
+			require_together,
+			max_files: rule.max_files,
+		})
+	}
+
+	/// `changed_files` is the PR's own total, which the fetched list may
+	/// fall short of.
+	fn check(&self, files: &[PullRequestFile], changed_files: u64) -> RuleResult {
+		let mut violations = Vec::new();
+
+		// Check max_files
+		let count = changed_files.max(files.len() as u64);
+		if let Some(max_files) = self.max_files
+			&& count > max_files as u64
+		{
+			violations.push(
+				Violation::error(
+					"files/max-files",
+					format!("PR changes {} files, maximum allowed: {}", count, max_files),
+				)
+				.with_suggestion("Split the change into smaller pull requests"),
+			);
+		}
+
+		// Check forbidden paths; deleting such a file is welcome
+		for file in files.iter().filter(|f| !f.is_removed()) {
+			let matched = self.forbidden.matches(&file.filename);
+			if let Some(&index) = matched.first() {
+				violations.push(
+					Violation::error(
+						"files/forbidden",
+						format!(
+							"File '{}' matches forbidden pattern '{}'",
+							file.filename, self.forbidden_patterns[index]
+						),
+					)
+					.with_location(&file.filename, None)
+					.with_suggestion("Remove this file from the pull request"),
+				);
+			}
+		}
+
+		// Check paths that must change together
+		for pair in &self.require_together {
+			let trigger = files.iter().find(|f| touches(f, &pair.changed));
+			if let Some(trigger) = trigger
+				&& !files.iter().any(|f| touches(f, &pair.requires))
+			{
+				violations.push(
+					Violation::error(
+						"files/require-together",
+						format!(
+							"Changing '{}' (matches '{}') requires also changing '{}'",
+							trigger.filename,
+							pair.changed.glob(),
+							pair.requires.glob()
+						),
+					)
+					.with_location(&trigger.filename, None),
+				);
+			}
+		}
+
+		violations
+	}
+}
+
+/// Whether a change touches a path, counting the old path of renames.
+fn touches(file: &PullRequestFile, matcher: &GlobMatcher) -> bool {
+	matcher.is_match(&file.filename)
+		|| file
+			.previous_filename
+			.as_deref()
+			.is_some_and(|p| matcher.is_match(p))
+}
+
+impl ConfiguredRule for FileCheck {
+	const ID: &'static str = "files";
+	type Config = FileRule;
+
+	fn section(config: &Config) -> Option<&FileRule> {
+		config.files.as_ref()
+	}
+
+	fn from_config(rule: &FileRule, _: &Config) -> crate::error::Result<Self> {
+		Self::new(rule)
+	}
+}
+
+#[async_trait]
+impl Rule for FileCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"changed files hygiene"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(self.check(ctx.files().await?, ctx.pr.changed_files))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::PullRequest;
+
+	fn create_files(names: Vec<&str>) -> Vec<PullRequestFile> {
+		names
+			.into_iter()
+			.map(|name| PullRequestFile {
+				filename: name.to_string(),
+				..Default::default()
+			})
+			.collect()
+	}
+
+	fn create_check(yaml: &str) -> FileCheck {
+		let rule: FileRule = serde_yaml::from_str(yaml).unwrap();
+		FileCheck::new(&rule).unwrap()
+	}
+
+	#[test]
+	fn test_forbidden_glob() {
+		let check = create_check("forbidden: [\"**/*.pem\"]\n");
+		let violations = check.check(&create_files(vec!["src/main.rs", "certs/dev/key.pem"]), 0);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "files/forbidden");
+		assert_eq!(
+			violations[0].location.as_ref().unwrap().file,
+			"certs/dev/key.pem"
+		);
+	}
+
+	#[test]
+	fn test_forbidden_allows_removal() {
+		let check = create_check("forbidden: [\"**/*.pem\"]\n");
+		let files = vec![PullRequestFile {
+			filename: "certs/dev/key.pem".to_string(),
+			status: "removed".to_string(),
+			..Default::default()
+		}];
+		assert!(check.check(&files, 0).is_empty());
+	}
+
+	#[test]
+	fn test_single_star_stays_in_directory() {
+		let check = create_check("forbidden: [\"secrets/*\"]\n");
+		assert!(
+			check
+				.check(&create_files(vec!["secrets/nested/a.txt"]), 0)
+				.is_empty()
+		);
+		assert_eq!(
+			check.check(&create_files(vec!["secrets/a.txt"]), 0).len(),
+			1
+		);
+	}
+
+	#[test]
+	fn test_require_together() {
+		let check = create_check(
+			"require_together:\n  - changed: \"migrations/**\"\n    requires: CHANGELOG.md\n",
+		);
+		let violations = check.check(&create_files(vec!["migrations/001_init.sql"]), 0);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "files/require-together");
+
+		let files = create_files(vec!["migrations/001_init.sql", "CHANGELOG.md"]);
+		assert!(check.check(&files, 0).is_empty());
+		assert!(check.check(&create_files(vec!["src/lib.rs"]), 0).is_empty());
+	}
+
+	#[test]
+	fn test_renamed_file_counts_old_path() {
+		let check = create_check(
+			"require_together:\n  - changed: \"migrations/**\"\n    requires: CHANGELOG.md\n",
+		);
+		let files = vec![PullRequestFile {
+			filename: "archive/001_init.sql".to_string(),
+			previous_filename: Some("migrations/001_init.sql".to_string()),
+			..Default::default()
+		}];
+		assert_eq!(check.check(&files, 0).len(), 1);
+	}
+
+	#[test]
+	fn test_max_files() {
+		let check = create_check("max_files: 2\n");
+		let violations = check.check(&create_files(vec!["a", "b", "c"]), 0);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "files/max-files");
+	}
+
+	#[test]
+	fn test_max_files_counts_unlisted_files() {
+		let check = create_check("max_files: 3000\n");
+		let violations = check.check(&create_files(vec!["a"]), 5000);
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.starts_with("PR changes 5000 files"));
+	}
+
+	#[tokio::test]
+	async fn test_evaluate_uses_context_files() {
+		let check = create_check("forbidden: [\"**/*.pem\"]\n");
+		let ctx = PrContext::new(PullRequest::default()).with_files(create_files(vec!["a.pem"]));
+		assert_eq!(check.evaluate(&ctx).await.unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_invalid_glob() {
+		let rule: FileRule = serde_yaml::from_str("forbidden: [\"a/{b\"]\n").unwrap();
+		assert!(FileCheck::new(&rule).is_err());
+	}
+}
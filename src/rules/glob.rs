@@ -0,0 +1,38 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use globset::{Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+
+/// Compile a path glob where `*` stays within one directory and `**` spans
+/// any number of them.
+fn build(pattern: &str) -> crate::error::Result<Glob> {
+	GlobBuilder::new(pattern)
+		.literal_separator(true)
+		.build()
+		.map_err(|e| crate::error::Error::Config(format!("Invalid glob '{}': {}", pattern, e)))
+}
+
+pub fn matcher(pattern: &str) -> crate::error::Result<GlobMatcher> {
+	Ok(build(pattern)?.compile_matcher())
+}
+
+pub fn set(patterns: &[String]) -> crate::error::Result<GlobSet> {
+	let mut builder = GlobSetBuilder::new();
+	for pattern in patterns {
+		builder.add(build(pattern)?);
+	}
+	builder
+		.build()
+		.map_err(|e| crate::error::Error::Config(format!("Invalid glob set: {}", e)))
+}
@@ -12,41 +12,206 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::config::LabelRule;
-use crate::github::PullRequest;
-use crate::rules::{RuleResult, Violation};
+use crate::config::{Config, LabelGroup, LabelRule};
+use crate::github::{PrContext, PullRequest};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, format_labels};
+use async_trait::async_trait;
+use regex::Regex;
+
+pub struct LabelCheck {
+	rule: LabelRule,
+}
+
+impl LabelCheck {
+	pub fn new(rule: LabelRule) -> Self {
+		Self { rule }
+	}
+}
+
+impl ConfiguredRule for LabelCheck {
+	const ID: &'static str = "labels";
+	type Config = LabelRule;
+
+	fn section(config: &Config) -> Option<&LabelRule> {
+		config.labels.as_ref()
+	}
+
+	fn from_config(rule: &LabelRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for LabelCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"required, forbidden and grouped PR labels"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(check_labels(&ctx.pr, &self.rule))
+	}
+}
 
 pub fn check_labels(pr: &PullRequest, rule: &LabelRule) -> RuleResult {
 	let mut violations = Vec::new();
+	let pr_label_names: Vec<String> = pr.labels.iter().map(|l| l.name.clone()).collect();
+	let current = format_labels(pr);
 
-	// If required is empty or None, skip all label checks
-	if let Some(required) = &rule.required {
-		if required.is_empty() {
-			return violations;
+	// An empty required list means no required labels
+	for required_label in rule.required.iter().flatten() {
+		if !pr_label_names.contains(required_label) {
+			violations.push(
+				Violation::error(
+					"labels/required",
+					format!(
+						"PR is missing required label: '{}'. Current labels: [{}]",
+						required_label, current
+					),
+				)
+				.with_suggestion(format!("Add the '{}' label", required_label)),
+			);
 		}
+	}
 
-		let pr_label_names: Vec<String> = pr.labels.iter().map(|l| l.name.clone()).collect();
+	let present = |set: &[String]| -> Vec<String> {
+		set.iter()
+			.filter(|l| pr_label_names.contains(l))
+			.cloned()
+			.collect()
+	};
 
-		for required_label in required {
-			if !pr_label_names.contains(required_label) {
-				violations.push(Violation {
-					message: format!(
-						"PR is missing required label: '{}'. Current labels: [{}]",
-						required_label,
-						if pr_label_names.is_empty() {
-							"none".to_string()
-						} else {
-							pr_label_names.join(", ")
-						}
-					),
-				});
-			}
+	// Check one_of: exactly one label of the set
+	if let Some(set) = rule.one_of.as_deref().filter(|s| !s.is_empty()) {
+		let found = present(set);
+		if found.len() != 1 {
+			violations.push(Violation::error(
+				"labels/one-of",
+				format!(
+					"PR must have exactly one of the labels [{}], found {}. Current labels: [{}]",
+					set.join(", "),
+					found.len(),
+					current
+				),
+			));
 		}
 	}
 
+	// Check at_least_one_of
+	if let Some(set) = rule.at_least_one_of.as_deref().filter(|s| !s.is_empty())
+		&& present(set).is_empty()
+	{
+		violations.push(
+			Violation::error(
+				"labels/at-least-one-of",
+				format!(
+					"PR must have at least one of the labels [{}]. Current labels: [{}]",
+					set.join(", "),
+					current
+				),
+			)
+			.with_suggestion(format!("Add one of: {}", set.join(", "))),
+		);
+	}
+
+	// Check at_most_one_of
+	if let Some(set) = &rule.at_most_one_of {
+		let found = present(set);
+		if found.len() > 1 {
+			violations.push(Violation::error(
+				"labels/at-most-one-of",
+				format!(
+					"PR must have at most one of the labels [{}], found: [{}]",
+					set.join(", "),
+					found.join(", ")
+				),
+			));
+		}
+	}
+
+	// Check forbidden labels
+	if let Some(set) = &rule.forbidden {
+		for label in present(set) {
+			violations.push(
+				Violation::error(
+					"labels/forbidden",
+					format!("PR has forbidden label: '{}'", label),
+				)
+				.with_suggestion(format!("Remove the '{}' label", label)),
+			);
+		}
+	}
+
+	// Check prefix/regex groups
+	for group in rule.groups.iter().flatten() {
+		violations.extend(check_group(&pr_label_names, group));
+	}
+
 	violations
 }
 
+fn check_group(pr_label_names: &[String], group: &LabelGroup) -> RuleResult {
+	let (description, found): (String, Vec<&String>) = match (&group.prefix, &group.pattern) {
+		(Some(prefix), None) => (
+			format!("prefix '{}'", prefix),
+			pr_label_names
+				.iter()
+				.filter(|l| l.starts_with(prefix.as_str()))
+				.collect(),
+		),
+		(None, Some(pattern)) => match Regex::new(pattern) {
+			Ok(re) => (
+				format!("pattern '{}'", pattern),
+				pr_label_names.iter().filter(|l| re.is_match(l)).collect(),
+			),
+			Err(e) => {
+				return vec![Violation::error(
+					"labels/group",
+					format!("Invalid regex pattern '{}': {}", pattern, e),
+				)];
+			}
+		},
+		_ => {
+			return vec![Violation::error(
+				"labels/group",
+				"Label group must set exactly one of `prefix` or `pattern`",
+			)];
+		}
+	};
+
+	let count = found.len();
+	let too_few = group.min.is_some_and(|min| count < min);
+	let too_many = group.max.is_some_and(|max| count > max);
+	if !too_few && !too_many {
+		return Vec::new();
+	}
+
+	let bounds = match (group.min, group.max) {
+		(Some(min), Some(max)) if min == max => format!("exactly {}", min),
+		(Some(min), Some(max)) => format!("between {} and {}", min, max),
+		(Some(min), None) => format!("at least {}", min),
+		(None, Some(max)) => format!("at most {}", max),
+		(None, None) => unreachable!("bounds were checked above"),
+	};
+	vec![Violation::error(
+		"labels/group",
+		format!(
+			"PR must have {} label(s) matching {}, found {}: [{}]",
+			bounds,
+			description,
+			count,
+			found
+				.iter()
+				.map(|l| l.as_str())
+				.collect::<Vec<_>>()
+				.join(", ")
+		),
+	)]
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -62,6 +227,7 @@ mod tests {
 					name: name.to_string(),
 				})
 				.collect(),
+			..Default::default()
 		}
 	}
 
@@ -70,6 +236,7 @@ mod tests {
 		let pr = create_pr_with_labels(vec!["kind/bug", "priority/high"]);
 		let rule = LabelRule {
 			required: Some(vec!["kind/bug".to_string(), "priority/high".to_string()]),
+			..Default::default()
 		};
 
 		let violations = check_labels(&pr, &rule);
@@ -81,10 +248,12 @@ mod tests {
 		let pr = create_pr_with_labels(vec!["kind/bug"]);
 		let rule = LabelRule {
 			required: Some(vec!["kind/bug".to_string(), "priority/high".to_string()]),
+			..Default::default()
 		};
 
 		let violations = check_labels(&pr, &rule);
 		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "labels/required");
 		assert!(violations[0].message.contains("priority/high"));
 	}
 
@@ -93,6 +262,7 @@ mod tests {
 		let pr = create_pr_with_labels(vec![]);
 		let rule = LabelRule {
 			required: Some(vec!["kind/bug".to_string()]),
+			..Default::default()
 		};
 
 		let violations = check_labels(&pr, &rule);
@@ -103,7 +273,10 @@ mod tests {
 	#[test]
 	fn test_no_required_labels() {
 		let pr = create_pr_with_labels(vec!["kind/bug"]);
-		let rule = LabelRule { required: None };
+		let rule = LabelRule {
+			required: None,
+			..Default::default()
+		};
 
 		let violations = check_labels(&pr, &rule);
 		assert!(violations.is_empty());
@@ -114,9 +287,93 @@ mod tests {
 		let pr = create_pr_with_labels(vec!["kind/bug"]);
 		let rule = LabelRule {
 			required: Some(vec![]),
+			..Default::default()
 		};
 
 		let violations = check_labels(&pr, &rule);
 		assert!(violations.is_empty());
 	}
+
+	#[test]
+	fn test_one_of() {
+		let rule = LabelRule {
+			one_of: Some(vec![
+				"priority/high".to_string(),
+				"priority/low".to_string(),
+			]),
+			..Default::default()
+		};
+
+		let pr = create_pr_with_labels(vec!["priority/high"]);
+		assert!(check_labels(&pr, &rule).is_empty());
+
+		for labels in [vec![], vec!["priority/high", "priority/low"]] {
+			let violations = check_labels(&create_pr_with_labels(labels), &rule);
+			assert_eq!(violations.len(), 1);
+			assert_eq!(violations[0].rule_id, "labels/one-of");
+		}
+	}
+
+	#[test]
+	fn test_at_least_and_at_most_one_of() {
+		let rule = LabelRule {
+			at_least_one_of: Some(vec!["area/api".to_string(), "area/web".to_string()]),
+			at_most_one_of: Some(vec!["area/api".to_string(), "area/web".to_string()]),
+			..Default::default()
+		};
+
+		let violations = check_labels(&create_pr_with_labels(vec![]), &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "labels/at-least-one-of");
+
+		let pr = create_pr_with_labels(vec!["area/api", "area/web"]);
+		let violations = check_labels(&pr, &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "labels/at-most-one-of");
+	}
+
+	#[test]
+	fn test_forbidden() {
+		let rule = LabelRule {
+			forbidden: Some(vec!["do-not-merge".to_string()]),
+			..Default::default()
+		};
+
+		let violations = check_labels(&create_pr_with_labels(vec!["do-not-merge"]), &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "labels/forbidden");
+	}
+
+	#[test]
+	fn test_pattern_group() {
+		let rule: LabelRule =
+			serde_yaml::from_str("groups:\n  - pattern: \"^kind/\"\n    min: 1\n    max: 1\n")
+				.unwrap();
+
+		assert!(check_labels(&create_pr_with_labels(vec!["kind/bug", "size/S"]), &rule).is_empty());
+
+		let pr = create_pr_with_labels(vec!["kind/bug", "kind/feature"]);
+		let violations = check_labels(&pr, &rule);
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.contains("exactly 1"));
+	}
+
+	#[test]
+	fn test_prefix_group() {
+		let rule: LabelRule =
+			serde_yaml::from_str("groups:\n  - prefix: \"priority/\"\n    min: 1\n").unwrap();
+
+		let violations = check_labels(&create_pr_with_labels(vec!["kind/bug"]), &rule);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "labels/group");
+	}
+
+	#[test]
+	fn test_invalid_group() {
+		let rule: LabelRule = serde_yaml::from_str("groups:\n  - min: 1\n").unwrap();
+
+		let violations = check_labels(&create_pr_with_labels(vec![]), &rule);
+		assert_eq!(violations.len(), 1);
+		assert!(violations[0].message.contains("exactly one of"));
+	}
 }
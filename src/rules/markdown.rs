@@ -0,0 +1,209 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal markdown helpers for PR bodies and templates.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+	pub level: usize,
+	pub text: String,
+	/// 1-based line number in the source text
+	pub line: usize,
+}
+
+/// A heading and everything beneath it up to the next heading of the same
+/// or a higher level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+	pub heading: Heading,
+	pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkbox {
+	pub checked: bool,
+	pub text: String,
+}
+
+/// Lines outside fenced code blocks, paired with their 0-based index.
+fn prose_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+	let mut in_fence = false;
+	text.lines().enumerate().filter(move |(_, line)| {
+		let trimmed = line.trim_start();
+		if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+			in_fence = !in_fence;
+			return false;
+		}
+		!in_fence
+	})
+}
+
+/// Parse an ATX heading such as `## Test plan`.
+pub fn parse_heading(line: &str) -> Option<(usize, String)> {
+	let trimmed = line.trim();
+	let level = trimmed.chars().take_while(|&c| c == '#').count();
+	if level == 0 || level > 6 {
+		return None;
+	}
+	let rest = &trimmed[level..];
+	if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+		return None;
+	}
+	Some((level, rest.trim().trim_end_matches('#').trim().to_string()))
+}
+
+pub fn headings(text: &str) -> Vec<Heading> {
+	prose_lines(&blank_comments(text))
+		.filter_map(|(i, line)| {
+			parse_heading(line).map(|(level, text)| Heading {
+				level,
+				text,
+				line: i + 1,
+			})
+		})
+		.collect()
+}
+
+pub fn sections(text: &str) -> Vec<Section> {
+	let lines: Vec<&str> = text.lines().collect();
+	let found = headings(text);
+	found
+		.iter()
+		.enumerate()
+		.map(|(i, heading)| {
+			let end = found[i + 1..]
+				.iter()
+				.find(|h| h.level <= heading.level)
+				.map_or(lines.len(), |h| h.line - 1);
+			Section {
+				heading: heading.clone(),
+				content: lines[heading.line..end].join("\n"),
+			}
+		})
+		.collect()
+}
+
+/// Remove `<!-- ... -->` comments, which GitHub does not render.
+pub fn strip_comments(text: &str) -> String {
+	remove_comments(text, false)
+}
+
+/// Like [`strip_comments`], but keeps the line breaks inside each comment so
+/// line numbers still match the source text.
+fn blank_comments(text: &str) -> String {
+	remove_comments(text, true)
+}
+
+fn remove_comments(text: &str, keep_lines: bool) -> String {
+	let mut out = String::with_capacity(text.len());
+	let mut rest = text;
+	while let Some(start) = rest.find("<!--") {
+		out.push_str(&rest[..start]);
+		let (comment, tail) = match rest[start..].find("-->") {
+			Some(end) => (&rest[start..start + end], &rest[start + end + 3..]),
+			None => (&rest[start..], ""),
+		};
+		if keep_lines {
+			out.extend(comment.matches('\n'));
+		}
+		rest = tail;
+	}
+	out.push_str(rest);
+	out
+}
+
+/// Task list items such as `- [x] Tests added`.
+pub fn checkboxes(text: &str) -> Vec<Checkbox> {
+	prose_lines(&blank_comments(text))
+		.filter_map(|(_, line)| {
+			let item = line.trim_start();
+			let item = item
+				.strip_prefix("- ")
+				.or_else(|| item.strip_prefix("* "))
+				.or_else(|| item.strip_prefix("+ "))?;
+			let (checked, text) = if let Some(rest) = item.strip_prefix("[ ]") {
+				(false, rest)
+			} else if let Some(rest) = item
+				.strip_prefix("[x]")
+				.or_else(|| item.strip_prefix("[X]"))
+			{
+				(true, rest)
+			} else {
+				return None;
+			};
+			Some(Checkbox {
+				checked,
+				text: text.trim().to_string(),
+			})
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_headings() {
+		let text = "## Summary\ntext\n```\n## not a heading\n```\n### Test plan ###\n#hashtag";
+		let found = headings(text);
+		assert_eq!(found.len(), 2);
+		assert_eq!(found[0].text, "Summary");
+		assert_eq!(found[0].level, 2);
+		assert_eq!(found[1].text, "Test plan");
+		assert_eq!(found[1].level, 3);
+		assert_eq!(found[1].line, 6);
+	}
+
+	#[test]
+	fn test_sections() {
+		let text = "intro\n## Summary\nfoo\n### Detail\nbar\n## Test plan\nran it";
+		let found = sections(text);
+		assert_eq!(found.len(), 3);
+		assert_eq!(found[0].heading.text, "Summary");
+		assert_eq!(found[0].content, "foo\n### Detail\nbar");
+		assert_eq!(found[1].content, "bar");
+		assert_eq!(found[2].content, "ran it");
+	}
+
+	#[test]
+	fn test_strip_comments() {
+		assert_eq!(strip_comments("a<!-- hint -->b<!--\nmulti\n-->c"), "abc");
+		assert_eq!(strip_comments("a<!-- open"), "a");
+	}
+
+	#[test]
+	fn test_commented_markdown_is_ignored() {
+		let text = "## Summary\n<!--\n## Hidden\n- [ ] Hidden task\n-->\n## Checklist\n- [x] Tests <!-- - [ ] inline -->";
+		let found = headings(text);
+		assert_eq!(found.len(), 2);
+		assert_eq!(found[1].text, "Checklist");
+		assert_eq!(found[1].line, 6);
+		let boxes = checkboxes(text);
+		assert_eq!(boxes.len(), 1);
+		assert_eq!(boxes[0].text, "Tests");
+		assert!(checkboxes("<!--\n- [ ] Open comment").is_empty());
+	}
+
+	#[test]
+	fn test_checkboxes() {
+		let text = "- [x] Tests added\n* [ ] Docs updated\n- plain item\n  - [X] nested";
+		let found = checkboxes(text);
+		assert_eq!(found.len(), 3);
+		assert!(found[0].checked);
+		assert!(!found[1].checked);
+		assert_eq!(found[1].text, "Docs updated");
+		assert!(found[2].checked);
+	}
+}
@@ -12,15 +12,161 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod alignment;
+pub mod body;
+pub mod branch;
+pub mod commits;
+pub mod condition;
+pub mod conventional;
+pub mod dco;
+pub mod exemption;
+pub mod files;
+pub mod glob;
 pub mod labels;
+pub mod markdown;
+pub mod references;
+pub mod registry;
+pub mod size;
+pub mod state;
+pub mod template;
 pub mod title;
 
-pub use labels::check_labels;
-pub use title::check_title;
+pub use alignment::AlignmentCheck;
+pub use body::BodyCheck;
+pub use branch::BranchCheck;
+pub use commits::CommitCheck;
+pub use dco::DcoCheck;
+pub use files::FileCheck;
+pub use labels::LabelCheck;
+pub use references::ReferenceCheck;
+pub use registry::RuleRegistry;
+pub use size::SizeCheck;
+pub use state::StateCheck;
+pub use template::TemplateCheck;
+pub use title::TitleCheck;
+
+use crate::config::{Config, RuleSection};
+use crate::github::{PrContext, PullRequest};
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+	Notice,
+	Warning,
+	Error,
+}
+
+impl Severity {
+	/// GitHub Actions workflow command used to annotate this severity.
+	pub fn annotation_level(self) -> &'static str {
+		match self {
+			Severity::Notice => "notice",
+			Severity::Warning => "warning",
+			Severity::Error => "error",
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Location {
+	pub file: String,
+	pub line: Option<usize>,
+}
 
 #[derive(Debug, Clone)]
 pub struct Violation {
+	/// Stable id of the check that failed, e.g. `title/pattern`
+	pub rule_id: String,
+	pub severity: Severity,
 	pub message: String,
+	pub location: Option<Location>,
+	/// Optional hint on how to fix the violation
+	pub suggestion: Option<String>,
+}
+
+impl Violation {
+	pub fn new(rule_id: impl Into<String>, severity: Severity, message: impl Into<String>) -> Self {
+		Self {
+			rule_id: rule_id.into(),
+			severity,
+			message: message.into(),
+			location: None,
+			suggestion: None,
+		}
+	}
+
+	pub fn error(rule_id: impl Into<String>, message: impl Into<String>) -> Self {
+		Self::new(rule_id, Severity::Error, message)
+	}
+
+	pub fn warning(rule_id: impl Into<String>, message: impl Into<String>) -> Self {
+		Self::new(rule_id, Severity::Warning, message)
+	}
+
+	pub fn notice(rule_id: impl Into<String>, message: impl Into<String>) -> Self {
+		Self::new(rule_id, Severity::Notice, message)
+	}
+
+	pub fn with_location(mut self, file: impl Into<String>, line: Option<usize>) -> Self {
+		self.location = Some(Location {
+			file: file.into(),
+			line,
+		});
+		self
+	}
+
+	pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+		self.suggestion = Some(suggestion.into());
+		self
+	}
 }
 
 pub type RuleResult = Vec<Violation>;
+
+/// A single check evaluated by the engine against a pull request.
+///
+/// Each rule is built from its own section of `pr-checker.yml` and is looked
+/// up by `id()` when the config lists an explicit `rules:` order.
+#[async_trait]
+pub trait Rule: Send + Sync {
+	/// Stable identifier, also the config section name (e.g. `title`).
+	fn id(&self) -> &'static str;
+
+	/// Short human-readable summary, used in logs.
+	fn description(&self) -> &'static str;
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult>;
+}
+
+/// A rule built from its config section, listed once in the registry's
+/// rule table.
+pub trait ConfiguredRule: Rule + Sized + 'static {
+	/// Id returned by `Rule::id`.
+	const ID: &'static str;
+
+	/// Type of the rule's config section, e.g. `TitleRule` for `title:`.
+	type Config: RuleSection;
+
+	/// The rule's section, or `None` when the rule is not configured.
+	fn section(config: &Config) -> Option<&Self::Config>;
+
+	/// Build the rule from its section; `config` gives access to shared
+	/// settings such as `type_labels`.
+	fn from_config(section: &Self::Config, config: &Config) -> crate::error::Result<Self>;
+}
+
+pub fn has_label(pr: &PullRequest, name: &str) -> bool {
+	pr.labels.iter().any(|l| l.name == name)
+}
+
+pub fn format_labels(pr: &PullRequest) -> String {
+	if pr.labels.is_empty() {
+		"none".to_string()
+	} else {
+		pr.labels
+			.iter()
+			.map(|l| l.name.clone())
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+}
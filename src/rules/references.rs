@@ -0,0 +1,362 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, ReferenceRule, ReferenceSource};
+use crate::github::{PrContext, PullRequest};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, has_label};
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+/// Ticket patterns used when none are configured: `JIRA-123` and `#123`.
+const DEFAULT_PATTERNS: &[&str] = &[r"\b[A-Z][A-Z0-9]+-\d+\b", r"(?:^|[^\w/&])#\d+\b"];
+
+/// Prefixes of `ABC-123` shaped tokens that name standards, encodings or
+/// advisories rather than tickets, e.g. `UTF-8` or `CVE-2024`.
+const NON_TICKET_KEYS: &[&str] = &[
+	"AES", "BASE", "CVE", "CWE", "ECMA", "ES", "GMT", "HTTP", "IEEE", "ISO", "MD", "PEP", "RFC",
+	"SHA", "TLS", "UTC", "UTF", "WIN",
+];
+
+/// Same-repository issue references such as `#123` or `Fixes #123`.
+static ISSUE_REF: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r"(?:^|[^\w/&])#(\d+)\b").expect("valid issue regex"));
+
+pub struct ReferenceCheck {
+	rule: ReferenceRule,
+	patterns: Vec<Regex>,
+	/// Ignore `NON_TICKET_KEYS` matches, only done for the default patterns
+	skip_non_tickets: bool,
+}
+
+impl ReferenceCheck {
+	pub fn new(rule: &ReferenceRule) -> crate::error::Result<Self> {
+		let patterns = match &rule.patterns {
+			Some(patterns) => patterns.iter().map(String::as_str).collect(),
+			None => DEFAULT_PATTERNS.to_vec(),
+		};
+		let patterns = patterns
+			.into_iter()
+			.map(|p| {
+				Regex::new(p).map_err(|e| {
+					crate::error::Error::Config(format!("Invalid regex pattern '{}': {}", p, e))
+				})
+			})
+			.collect::<crate::error::Result<Vec<_>>>()?;
+
+		Ok(Self {
+			rule: rule.clone(),
+			patterns,
+			skip_non_tickets: rule.patterns.is_none(),
+		})
+	}
+
+	/// Text of every configured source, e.g. title, body and branch name.
+	fn sources<'p>(&self, pr: &'p PullRequest) -> Vec<&'p str> {
+		let sources = self.rule.sources.as_deref().unwrap_or(&[
+			ReferenceSource::Title,
+			ReferenceSource::Body,
+			ReferenceSource::Branch,
+		]);
+		sources
+			.iter()
+			.map(|source| match source {
+				ReferenceSource::Title => pr.title.as_str(),
+				ReferenceSource::Body => pr.body.as_deref().unwrap_or_default(),
+				ReferenceSource::Branch => pr.head.name.as_str(),
+			})
+			.collect()
+	}
+
+	/// Whether any configured pattern matches one of `texts`.
+	fn mentions_ticket<T: AsRef<str>>(&self, texts: &[T]) -> bool {
+		texts.iter().any(|text| {
+			self.patterns
+				.iter()
+				.flat_map(|re| re.find_iter(text.as_ref()))
+				.any(|m| !(self.skip_non_tickets && is_non_ticket(m.as_str())))
+		})
+	}
+
+	fn check(&self, pr: &PullRequest) -> RuleResult {
+		if self
+			.rule
+			.exempt_labels
+			.iter()
+			.flatten()
+			.any(|l| has_label(pr, l))
+		{
+			return Vec::new();
+		}
+
+		if self.mentions_ticket(&self.sources(pr)) {
+			return Vec::new();
+		}
+
+		vec![
+			Violation::error(
+				"references/missing",
+				format!(
+					"PR does not reference a ticket; expected a match for one of: {}",
+					self.patterns
+						.iter()
+						.map(Regex::as_str)
+						.collect::<Vec<_>>()
+						.join(", ")
+				),
+			)
+			.with_suggestion("Mention the ticket in the title or description, e.g. 'Fixes #123'"),
+		]
+	}
+}
+
+fn is_non_ticket(token: &str) -> bool {
+	token
+		.split_once('-')
+		.is_some_and(|(key, _)| NON_TICKET_KEYS.contains(&key))
+}
+
+/// GitHub issue numbers referenced in `texts`, deduplicated and sorted.
+fn issue_numbers(texts: &[&str]) -> BTreeSet<u64> {
+	texts
+		.iter()
+		.flat_map(|text| ISSUE_REF.captures_iter(text))
+		.filter_map(|c| c[1].parse().ok())
+		.collect()
+}
+
+impl ConfiguredRule for ReferenceCheck {
+	const ID: &'static str = "references";
+	type Config = ReferenceRule;
+
+	fn section(config: &Config) -> Option<&ReferenceRule> {
+		config.references.as_ref()
+	}
+
+	fn from_config(rule: &ReferenceRule, _: &Config) -> crate::error::Result<Self> {
+		Self::new(rule)
+	}
+}
+
+#[async_trait]
+impl Rule for ReferenceCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"PR references a tracker ticket"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		let mut violations = self.check(&ctx.pr);
+		if !violations.is_empty() || !self.rule.verify_issues {
+			return Ok(violations);
+		}
+
+		let client = ctx.client()?;
+		let texts = self.sources(&ctx.pr);
+		let mut pull_requests = Vec::new();
+		let mut open_issue = false;
+		for number in issue_numbers(&texts) {
+			match client.get_issue(number).await? {
+				None => violations.push(Violation::error(
+					"references/issue-not-found",
+					format!("Referenced issue #{} does not exist", number),
+				)),
+				// `/issues/{n}` also serves pull requests
+				Some(issue) if issue.is_pull_request() => pull_requests.push(number),
+				Some(issue) if issue.state != "open" => violations.push(Violation::error(
+					"references/issue-closed",
+					format!("Referenced issue #{} is {}", number, issue.state),
+				)),
+				Some(_) => open_issue = true,
+			}
+		}
+
+		// Mentioning related PRs is fine as long as a ticket is referenced
+		// too, by an open issue or by a pattern other than `#123`
+		let other_ticket = || {
+			let stripped: Vec<_> = texts
+				.iter()
+				.map(|t| ISSUE_REF.replace_all(t, " "))
+				.collect();
+			self.mentions_ticket(&stripped)
+		};
+		if !open_issue && !other_ticket() {
+			violations.extend(pull_requests.into_iter().map(|number| {
+				Violation::error(
+					"references/not-an-issue",
+					if number == ctx.pr.number {
+						format!("#{} refers to this PR, not an issue", number)
+					} else {
+						format!("Referenced #{} is a pull request, not an issue", number)
+					},
+				)
+				.with_suggestion("Reference the issue the change addresses")
+			}));
+		}
+
+		Ok(violations)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::{PullRequestLabel, PullRequestRef};
+
+	fn create_pr(title: &str, body: &str, branch: &str, labels: Vec<&str>) -> PullRequest {
+		PullRequest {
+			title: title.to_string(),
+			body: Some(body.to_string()),
+			head: PullRequestRef {
+				name: branch.to_string(),
+			},
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		}
+	}
+
+	fn create_check(yaml: &str) -> ReferenceCheck {
+		ReferenceCheck::new(&serde_yaml::from_str(yaml).unwrap()).unwrap()
+	}
+
+	#[test]
+	fn test_default_patterns() {
+		let check = create_check("{}");
+		assert!(
+			check
+				.check(&create_pr("fix: crash", "Fixes #12", "fix/crash", vec![]))
+				.is_empty()
+		);
+		assert!(
+			check
+				.check(&create_pr("fix: crash", "", "fix/PROJ-7-crash", vec![]))
+				.is_empty()
+		);
+
+		let violations = check.check(&create_pr("fix: crash", "see a&#39;b", "fix/crash", vec![]));
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "references/missing");
+
+		for body in [
+			"Decode as UTF-8",
+			"Hash with SHA-256",
+			"Dates are ISO-8601",
+			"Patches CVE-2024",
+		] {
+			let pr = create_pr("fix: crash", body, "fix/crash", vec![]);
+			assert_eq!(check.check(&pr).len(), 1, "{}", body);
+		}
+		let pr = create_pr("fix: crash", "UTF-8 fix for PROJ-7", "fix/crash", vec![]);
+		assert!(check.check(&pr).is_empty());
+	}
+
+	#[test]
+	fn test_sources() {
+		let check = create_check("sources: [title]");
+		let violations = check.check(&create_pr("fix: crash", "Fixes #12", "fix/crash", vec![]));
+		assert_eq!(violations.len(), 1);
+	}
+
+	#[test]
+	fn test_custom_patterns() {
+		let check = create_check("patterns: [\"OPS-\\\\d+\"]");
+		assert!(
+			check
+				.check(&create_pr("fix: OPS-9 crash", "", "fix", vec![]))
+				.is_empty()
+		);
+		assert_eq!(
+			check.check(&create_pr("fix: #9", "", "fix", vec![])).len(),
+			1
+		);
+	}
+
+	#[test]
+	fn test_exempt_labels() {
+		let check = create_check("exempt_labels: [dependencies]");
+		let pr = create_pr("chore: bump", "", "deps", vec!["dependencies"]);
+		assert!(check.check(&pr).is_empty());
+	}
+
+	#[test]
+	fn test_issue_numbers() {
+		let numbers = issue_numbers(&["Fixes #12, refs #3 and #12", "a/b#4 C#5 &#39;"]);
+		assert_eq!(numbers.into_iter().collect::<Vec<_>>(), vec![3, 12]);
+	}
+
+	#[tokio::test]
+	async fn test_verify_issues_pull_requests() {
+		let mut server = mockito::Server::new_async().await;
+		server
+			.mock("GET", "/repos/owner/repo/issues/3")
+			.with_body(r#"{"state": "open"}"#)
+			.create_async()
+			.await;
+		server
+			.mock("GET", "/repos/owner/repo/issues/4")
+			.with_body(r#"{"state": "open", "pull_request": {"url": "https://api.github.com/x"}}"#)
+			.create_async()
+			.await;
+		server
+			.mock("GET", "/repos/owner/repo/issues/7")
+			.with_body(r#"{"state": "open", "pull_request": {}}"#)
+			.create_async()
+			.await;
+
+		let client = crate::github::GitHubClient::new(
+			"t0ken".to_string(),
+			"owner".to_string(),
+			"repo".to_string(),
+		)
+		.with_api_url(server.url());
+		let check = create_check("verify_issues: true");
+		let messages = async |body: &str| {
+			let pr = PullRequest {
+				number: 7,
+				..create_pr("fix: crash", body, "fix", vec![])
+			};
+			let ctx = PrContext::new(pr).with_client(&client);
+			let violations = check.evaluate(&ctx).await.unwrap();
+			violations
+				.into_iter()
+				.map(|v| v.message)
+				.collect::<Vec<_>>()
+		};
+
+		assert!(messages("Fixes #3, follow-up to #4").await.is_empty());
+		assert!(messages("PROJ-9, follow-up to #4").await.is_empty());
+		assert_eq!(
+			messages("See #4 and #7").await,
+			vec![
+				"Referenced #4 is a pull request, not an issue",
+				"#7 refers to this PR, not an issue"
+			]
+		);
+	}
+
+	#[test]
+	fn test_invalid_pattern() {
+		let rule: ReferenceRule = serde_yaml::from_str("patterns: [\"(\"]").unwrap();
+		assert!(ReferenceCheck::new(&rule).is_err());
+	}
+}
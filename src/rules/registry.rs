@@ -0,0 +1,236 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, RuleSection, RuleSeverity, When};
+use crate::rules::condition::Condition;
+use crate::rules::{
+	AlignmentCheck, BodyCheck, BranchCheck, CommitCheck, ConfiguredRule, DcoCheck, FileCheck,
+	LabelCheck, ReferenceCheck, Rule, Severity, SizeCheck, StateCheck, TemplateCheck, TitleCheck,
+	Violation,
+};
+use tracing::{debug, warn};
+
+/// A known rule and how to build it from the config.
+struct RuleEntry {
+	id: &'static str,
+	build: fn(&Config) -> crate::error::Result<Option<RegisteredRule>>,
+}
+
+const fn entry<R: ConfiguredRule>() -> RuleEntry {
+	RuleEntry {
+		id: R::ID,
+		build: build::<R>,
+	}
+}
+
+/// Every rule the engine knows about, in default evaluation order.
+/// `state` always runs first since it may skip every other rule.
+const RULES: &[RuleEntry] = &[
+	entry::<StateCheck>(),
+	entry::<TitleCheck>(),
+	entry::<LabelCheck>(),
+	entry::<AlignmentCheck>(),
+	entry::<BodyCheck>(),
+	entry::<TemplateCheck>(),
+	entry::<FileCheck>(),
+	entry::<SizeCheck>(),
+	entry::<BranchCheck>(),
+	entry::<ReferenceCheck>(),
+	entry::<CommitCheck>(),
+	entry::<DcoCheck>(),
+];
+
+fn build<R: ConfiguredRule>(config: &Config) -> crate::error::Result<Option<RegisteredRule>> {
+	let Some(section) = R::section(config) else {
+		return Ok(None);
+	};
+	RegisteredRule::new(
+		Box::new(R::from_config(section, config)?),
+		section.severity(),
+		section.when(),
+	)
+}
+
+/// A rule together with the severity and `when:` condition its config
+/// section asked for.
+pub struct RegisteredRule {
+	pub rule: Box<dyn Rule>,
+	pub severity: RuleSeverity,
+	pub condition: Option<Condition>,
+}
+
+impl RegisteredRule {
+	/// Returns `None` for rules whose section sets `severity: off`.
+	fn new(
+		rule: Box<dyn Rule>,
+		severity: Option<RuleSeverity>,
+		when: Option<&When>,
+	) -> crate::error::Result<Option<Self>> {
+		let severity = severity.unwrap_or_default();
+		if severity == RuleSeverity::Off {
+			debug!("Rule '{}' is turned off", rule.id());
+			return Ok(None);
+		}
+		Ok(Some(Self {
+			rule,
+			severity,
+			condition: when.map(Condition::new).transpose()?,
+		}))
+	}
+
+	/// Downgrade errors reported by a rule configured as `warning`.
+	pub fn apply_severity(&self, mut violations: Vec<Violation>) -> Vec<Violation> {
+		if self.severity == RuleSeverity::Warning {
+			for v in &mut violations {
+				if v.severity == Severity::Error {
+					v.severity = Severity::Warning;
+				}
+			}
+		}
+		violations
+	}
+}
+
+/// The ordered set of rules the engine evaluates for a run.
+pub struct RuleRegistry {
+	rules: Vec<RegisteredRule>,
+}
+
+impl RuleRegistry {
+	/// Build rules from their config sections, honoring the optional
+	/// `rules:` list for ordering and enabling.
+	pub fn from_config(config: &Config) -> crate::error::Result<Self> {
+		let mut available = Vec::new();
+		for entry in RULES {
+			available.extend((entry.build)(config)?);
+		}
+
+		let Some(order) = &config.rules else {
+			return Ok(Self { rules: available });
+		};
+
+		let mut rules = Vec::new();
+		for (i, id) in order.iter().enumerate() {
+			if !RULES.iter().any(|e| e.id == id) {
+				return Err(crate::error::Error::Config(format!(
+					"Unknown rule '{}' in `rules`, expected one of: {}",
+					id,
+					RULES.iter().map(|e| e.id).collect::<Vec<_>>().join(", ")
+				)));
+			}
+			if order[..i].contains(id) {
+				return Err(crate::error::Error::Config(format!(
+					"Rule '{}' is listed more than once in `rules`",
+					id
+				)));
+			}
+
+			match available.iter().position(|r| r.rule.id() == id) {
+				Some(pos) => rules.push(available.remove(pos)),
+				None => warn!(
+					"Rule '{}' is enabled in `rules` but not configured, skipping",
+					id
+				),
+			}
+		}
+
+		// Rules that ran before a `state` skip would have their violations
+		// thrown away, so it goes first whatever `rules:` says
+		if let Some(pos) = rules.iter().position(|r| r.rule.id() == "state")
+			&& pos > 0
+		{
+			debug!("Moving rule 'state' ahead of the `rules` order");
+			let state = rules.remove(pos);
+			rules.insert(0, state);
+		}
+
+		Ok(Self { rules })
+	}
+
+	pub fn rules(&self) -> &[RegisteredRule] {
+		&self.rules
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ids(yaml: &str) -> Vec<&'static str> {
+		let config: Config = serde_yaml::from_str(yaml).unwrap();
+		let registry = RuleRegistry::from_config(&config).unwrap();
+		registry.rules().iter().map(|r| r.rule.id()).collect()
+	}
+
+	#[test]
+	fn test_default_order() {
+		let yaml = "title:\n  min_length: 10\nlabels:\n  required: []\n";
+		assert_eq!(ids(yaml), vec!["title", "labels", "alignment"]);
+	}
+
+	#[test]
+	fn test_every_rule_is_registered() {
+		let yaml = "state: {}\ntitle: {}\nlabels: {}\nbody: {}\ntemplate: {}\nfiles: {}\nsize: {}\nbranch: {}\nreferences: {}\ncommits: {}\ndco: {}\n";
+		let expected: Vec<&str> = RULES.iter().map(|e| e.id).collect();
+		assert_eq!(ids(yaml), expected);
+	}
+
+	#[test]
+	fn test_explicit_order_and_disable() {
+		let yaml = "rules: [labels, title]\ntitle:\n  min_length: 10\nlabels:\n  required: []\n";
+		assert_eq!(ids(yaml), vec!["labels", "title"]);
+	}
+
+	#[test]
+	fn test_state_runs_first() {
+		let yaml = "rules: [title, state]\ntitle:\n  min_length: 10\nstate:\n  draft: skip\n";
+		assert_eq!(ids(yaml), vec!["state", "title"]);
+	}
+
+	#[test]
+	fn test_unconfigured_rule_is_skipped() {
+		let yaml = "rules: [title, labels]\ntitle:\n  min_length: 10\n";
+		assert_eq!(ids(yaml), vec!["title"]);
+	}
+
+	#[test]
+	fn test_severity_off_disables_rule() {
+		let yaml = "title:\n  severity: off\n  min_length: 10\nlabels:\n  required: []\nalignment:\n  severity: off\n";
+		assert_eq!(ids(yaml), vec!["labels"]);
+	}
+
+	#[test]
+	fn test_warning_severity_downgrades_errors() {
+		let config: Config = serde_yaml::from_str("title:\n  severity: warning\n").unwrap();
+		let registry = RuleRegistry::from_config(&config).unwrap();
+		let violations = registry.rules()[0].apply_severity(vec![
+			Violation::error("title/pattern", "bad"),
+			Violation::notice("context", "info"),
+		]);
+		assert_eq!(violations[0].severity, Severity::Warning);
+		assert_eq!(violations[1].severity, Severity::Notice);
+	}
+
+	#[test]
+	fn test_unknown_rule() {
+		let config: Config = serde_yaml::from_str("rules: [nope]\n").unwrap();
+		assert!(RuleRegistry::from_config(&config).is_err());
+	}
+
+	#[test]
+	fn test_duplicate_rule() {
+		let config: Config = serde_yaml::from_str("rules: [title, title]\n").unwrap();
+		assert!(RuleRegistry::from_config(&config).is_err());
+	}
+}
@@ -0,0 +1,268 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, SizeRule};
+use crate::github::PrContext;
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, glob};
+use async_trait::async_trait;
+use globset::GlobSet;
+
+/// Excluded paths named in violation messages before the rest are counted.
+const MAX_LISTED_EXCLUSIONS: usize = 5;
+
+/// Lines and files changed by a PR, after exclusions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SizeStats {
+	pub additions: u64,
+	pub deletions: u64,
+	pub files: u64,
+	pub commits: u64,
+	/// Files left out because they matched an `exclude` glob
+	pub excluded: Vec<String>,
+}
+
+impl SizeStats {
+	pub fn lines(&self) -> u64 {
+		self.additions + self.deletions
+	}
+
+	/// How the numbers were computed, for violation messages.
+	pub fn explain(&self) -> String {
+		let mut text = format!(
+			"{} lines changed ({} additions + {} deletions) across {} files and {} commits",
+			self.lines(),
+			self.additions,
+			self.deletions,
+			self.files,
+			self.commits
+		);
+		if !self.excluded.is_empty() {
+			text.push_str(&format!(
+				", excluding {} file(s): {}",
+				self.excluded.len(),
+				self.excluded[..self.excluded.len().min(MAX_LISTED_EXCLUSIONS)].join(", ")
+			));
+			if self.excluded.len() > MAX_LISTED_EXCLUSIONS {
+				text.push_str(&format!(
+					" and {} more",
+					self.excluded.len() - MAX_LISTED_EXCLUSIONS
+				));
+			}
+		}
+		text
+	}
+}
+
+/// Measure the PR. Without exclusions the totals reported by the pulls API
+/// are used; otherwise the per-file numbers are summed.
+pub async fn measure(ctx: &PrContext<'_>, exclude: &GlobSet) -> crate::error::Result<SizeStats> {
+	let pr = &ctx.pr;
+	if exclude.is_empty() {
+		return Ok(SizeStats {
+			additions: pr.additions,
+			deletions: pr.deletions,
+			files: pr.changed_files,
+			commits: pr.commits,
+			excluded: Vec::new(),
+		});
+	}
+
+	let mut stats = SizeStats {
+		commits: pr.commits,
+		..Default::default()
+	};
+	for file in ctx.files().await? {
+		if exclude.is_match(&file.filename) {
+			stats.excluded.push(file.filename.clone());
+		} else {
+			stats.additions += file.additions;
+			stats.deletions += file.deletions;
+			stats.files += 1;
+		}
+	}
+	Ok(stats)
+}
+
+pub struct SizeCheck {
+	rule: SizeRule,
+	exclude: GlobSet,
+}
+
+impl SizeCheck {
+	pub fn new(rule: &SizeRule) -> crate::error::Result<Self> {
+		Ok(Self {
+			exclude: glob::set(rule.exclude.as_deref().unwrap_or_default())?,
+			rule: rule.clone(),
+		})
+	}
+
+	fn check(&self, stats: &SizeStats) -> RuleResult {
+		let limits = [
+			(
+				"size/additions",
+				"additions",
+				stats.additions,
+				self.rule.max_additions,
+			),
+			(
+				"size/deletions",
+				"deletions",
+				stats.deletions,
+				self.rule.max_deletions,
+			),
+			(
+				"size/lines",
+				"changed lines",
+				stats.lines(),
+				self.rule.max_lines,
+			),
+			(
+				"size/files",
+				"changed files",
+				stats.files,
+				self.rule.max_files,
+			),
+		];
+
+		limits
+			.into_iter()
+			.filter_map(|(id, what, actual, max)| {
+				let max = max.filter(|&max| actual > max)?;
+				Some(
+					Violation::error(
+						id,
+						format!(
+							"PR is too large: {} {}, maximum allowed: {}. Size: {}",
+							actual,
+							what,
+							max,
+							stats.explain()
+						),
+					)
+					.with_suggestion("Split the change into smaller pull requests"),
+				)
+			})
+			.collect()
+	}
+}
+
+impl ConfiguredRule for SizeCheck {
+	const ID: &'static str = "size";
+	type Config = SizeRule;
+
+	fn section(config: &Config) -> Option<&SizeRule> {
+		config.size.as_ref()
+	}
+
+	fn from_config(rule: &SizeRule, _: &Config) -> crate::error::Result<Self> {
+		Self::new(rule)
+	}
+}
+
+#[async_trait]
+impl Rule for SizeCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"PR size limits"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		let stats = measure(ctx, &self.exclude).await?;
+		Ok(self.check(&stats))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::{PullRequest, PullRequestFile};
+
+	fn create_ctx() -> PrContext<'static> {
+		let pr = PullRequest {
+			additions: 1200,
+			deletions: 50,
+			changed_files: 3,
+			commits: 2,
+			..Default::default()
+		};
+		let files = vec![
+			("Cargo.lock", 1000, 20),
+			("src/main.rs", 150, 20),
+			("vendor/lib/a.rs", 50, 10),
+		];
+		PrContext::new(pr).with_files(
+			files
+				.into_iter()
+				.map(|(name, additions, deletions)| PullRequestFile {
+					filename: name.to_string(),
+					additions,
+					deletions,
+					..Default::default()
+				})
+				.collect(),
+		)
+	}
+
+	fn create_check(yaml: &str) -> SizeCheck {
+		let rule: SizeRule = serde_yaml::from_str(yaml).unwrap();
+		SizeCheck::new(&rule).unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_uses_pr_totals_without_exclusions() {
+		let check = create_check("max_lines: 500\nmax_files: 5\n");
+		let violations = check.evaluate(&create_ctx()).await.unwrap();
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "size/lines");
+		assert!(
+			violations[0]
+				.message
+				.contains("1250 lines changed (1200 additions + 50 deletions)")
+		);
+	}
+
+	#[tokio::test]
+	async fn test_exclusions_use_file_list() {
+		let check = create_check("max_lines: 500\nexclude: [\"*.lock\", \"vendor/**\"]\n");
+		let ctx = create_ctx();
+		let stats = measure(&ctx, &check.exclude).await.unwrap();
+		assert_eq!(stats.lines(), 170);
+		assert_eq!(stats.files, 1);
+		assert_eq!(stats.excluded, vec!["Cargo.lock", "vendor/lib/a.rs"]);
+		assert!(check.evaluate(&ctx).await.unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_explain_lists_few_exclusions() {
+		let stats = SizeStats {
+			excluded: (0..1000).map(|i| format!("vendor/{}.rs", i)).collect(),
+			..Default::default()
+		};
+		assert!(stats.explain().ends_with(
+			"excluding 1000 file(s): vendor/0.rs, vendor/1.rs, vendor/2.rs, vendor/3.rs, vendor/4.rs and 995 more"
+		));
+	}
+
+	#[tokio::test]
+	async fn test_each_threshold() {
+		let check = create_check("max_additions: 100\nmax_deletions: 10\nmax_files: 1\n");
+		let violations = check.evaluate(&create_ctx()).await.unwrap();
+		let ids: Vec<&str> = violations.iter().map(|v| v.rule_id.as_str()).collect();
+		assert_eq!(ids, vec!["size/additions", "size/deletions", "size/files"]);
+		assert!(!violations[2].message.contains("excluding"));
+	}
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, StateAction, StateRule};
+use crate::github::{PrContext, PullRequest};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation, has_label};
+use async_trait::async_trait;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Title markers for PRs that are not ready: `WIP`, `[WIP]`, `DO NOT MERGE`.
+static NOT_READY_TITLE: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r"(?i)\bwip\b|\bdo[ -]not[ -]merge\b").expect("valid WIP regex"));
+
+pub struct StateCheck {
+	rule: StateRule,
+}
+
+/// A reason the PR is not ready, with what the config wants done about it.
+struct Finding {
+	action: StateAction,
+	rule_id: &'static str,
+	message: String,
+	suggestion: &'static str,
+}
+
+impl StateCheck {
+	pub fn new(rule: StateRule) -> Self {
+		Self { rule }
+	}
+
+	fn findings(&self, pr: &PullRequest) -> Vec<Finding> {
+		let mut findings = Vec::new();
+
+		if let Some(action) = self.rule.draft
+			&& pr.draft
+		{
+			findings.push(Finding {
+				action,
+				rule_id: "state/draft",
+				message: "PR is a draft".to_string(),
+				suggestion: "Mark the PR as ready for review",
+			});
+		}
+
+		if let Some(action) = self.rule.wip
+			&& NOT_READY_TITLE.is_match(&pr.title)
+		{
+			findings.push(Finding {
+				action,
+				rule_id: "state/wip",
+				message: format!("PR title '{}' marks it as not ready to merge", pr.title),
+				suggestion: "Remove the WIP or DO NOT MERGE marker from the title",
+			});
+		}
+
+		let action = self.rule.on_blocking_label.unwrap_or_default();
+		for label in self.rule.blocking_labels.iter().flatten() {
+			if has_label(pr, label) {
+				findings.push(Finding {
+					action,
+					rule_id: "state/blocking-label",
+					message: format!("PR has blocking label '{}'", label),
+					suggestion: "Remove the label once the PR is ready",
+				});
+			}
+		}
+
+		findings
+	}
+}
+
+impl ConfiguredRule for StateCheck {
+	const ID: &'static str = "state";
+	type Config = StateRule;
+
+	fn section(config: &Config) -> Option<&StateRule> {
+		config.state.as_ref()
+	}
+
+	fn from_config(rule: &StateRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for StateCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"draft, WIP and do-not-merge gating"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		let findings = self.findings(&ctx.pr);
+
+		if let Some(skip) = findings.iter().find(|f| f.action == StateAction::Skip) {
+			ctx.skip_all(skip.message.as_str());
+			return Ok(Vec::new());
+		}
+
+		Ok(findings
+			.into_iter()
+			.map(|f| Violation::error(f.rule_id, f.message).with_suggestion(f.suggestion))
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::github::pr::PullRequestLabel;
+
+	fn create_pr(title: &str, draft: bool, labels: Vec<&str>) -> PullRequest {
+		PullRequest {
+			title: title.to_string(),
+			draft,
+			labels: labels
+				.into_iter()
+				.map(|name| PullRequestLabel {
+					name: name.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		}
+	}
+
+	async fn evaluate(yaml: &str, pr: PullRequest) -> (Vec<String>, Option<String>) {
+		let check = StateCheck::new(serde_yaml::from_str(yaml).unwrap());
+		let ctx = PrContext::new(pr);
+		let ids = check
+			.evaluate(&ctx)
+			.await
+			.unwrap()
+			.into_iter()
+			.map(|v| v.rule_id)
+			.collect();
+		(ids, ctx.skip_reason().map(str::to_string))
+	}
+
+	#[test]
+	fn test_not_ready_titles() {
+		for title in [
+			"WIP: add rule",
+			"[WIP] add rule",
+			"feat: add rule (DO NOT MERGE)",
+		] {
+			assert!(NOT_READY_TITLE.is_match(title), "{}", title);
+		}
+		for title in ["fix: wipe cache", "feat: merge configs"] {
+			assert!(!NOT_READY_TITLE.is_match(title), "{}", title);
+		}
+	}
+
+	#[tokio::test]
+	async fn test_fail() {
+		let yaml = "draft: fail\nwip: fail\nblocking_labels: [do-not-merge]\n";
+		let pr = create_pr("WIP: add rule", true, vec!["do-not-merge"]);
+		let (ids, skipped) = evaluate(yaml, pr).await;
+		assert_eq!(
+			ids,
+			vec!["state/draft", "state/wip", "state/blocking-label"]
+		);
+		assert!(skipped.is_none());
+
+		let (ids, _) = evaluate(yaml, create_pr("feat: add rule", false, vec![])).await;
+		assert!(ids.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_skip_wins() {
+		let yaml = "draft: skip\nwip: fail\n";
+		let (ids, skipped) = evaluate(yaml, create_pr("WIP: add rule", true, vec![])).await;
+		assert!(ids.is_empty());
+		assert_eq!(skipped.as_deref(), Some("PR is a draft"));
+	}
+
+	#[tokio::test]
+	async fn test_blocking_label_skip() {
+		let yaml = "blocking_labels: [on-hold]\non_blocking_label: skip\n";
+		let (_, skipped) = evaluate(yaml, create_pr("feat: x", false, vec!["on-hold"])).await;
+		assert_eq!(skipped.as_deref(), Some("PR has blocking label 'on-hold'"));
+	}
+}
@@ -0,0 +1,270 @@
+// Copyright 2025 itscheems
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{Config, TemplateRule};
+use crate::github::PrContext;
+use crate::rules::markdown::{checkboxes, parse_heading, sections, strip_comments};
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation};
+use async_trait::async_trait;
+use std::path::Path;
+use tracing::debug;
+
+/// Files GitHub reads a single pull request template from.
+const TEMPLATE_FILES: &[&str] = &[
+	".github/pull_request_template.md",
+	".github/PULL_REQUEST_TEMPLATE.md",
+	"pull_request_template.md",
+	"PULL_REQUEST_TEMPLATE.md",
+	"docs/pull_request_template.md",
+	"docs/PULL_REQUEST_TEMPLATE.md",
+];
+
+/// Directories GitHub reads multiple pull request templates from.
+const TEMPLATE_DIRS: &[&str] = &[
+	".github/PULL_REQUEST_TEMPLATE",
+	"PULL_REQUEST_TEMPLATE",
+	"docs/PULL_REQUEST_TEMPLATE",
+];
+
+#[derive(Debug, Clone)]
+pub struct Template {
+	pub path: String,
+	pub content: String,
+}
+
+pub struct TemplateCheck {
+	rule: TemplateRule,
+}
+
+impl TemplateCheck {
+	pub fn new(rule: TemplateRule) -> Self {
+		Self { rule }
+	}
+}
+
+impl ConfiguredRule for TemplateCheck {
+	const ID: &'static str = "template";
+	type Config = TemplateRule;
+
+	fn section(config: &Config) -> Option<&TemplateRule> {
+		config.template.as_ref()
+	}
+
+	fn from_config(rule: &TemplateRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for TemplateCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"PR description keeps every pull request template section"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		let templates = load_templates(self.rule.path.as_deref())?;
+		if templates.is_empty() {
+			debug!("No pull request template found, skipping template check");
+			return Ok(Vec::new());
+		}
+
+		Ok(check_template(
+			ctx.pr.body.as_deref().unwrap_or_default(),
+			&templates,
+		))
+	}
+}
+
+/// Load the configured template, or every template GitHub would offer.
+pub fn load_templates(configured: Option<&str>) -> crate::error::Result<Vec<Template>> {
+	if let Some(path) = configured {
+		let content = std::fs::read_to_string(path).map_err(|e| {
+			crate::error::Error::Config(format!("Failed to read PR template '{}': {}", path, e))
+		})?;
+		return Ok(vec![Template {
+			path: path.to_string(),
+			content,
+		}]);
+	}
+
+	let mut templates = Vec::new();
+	for path in TEMPLATE_FILES {
+		if Path::new(path).is_file() {
+			templates.push(Template {
+				path: path.to_string(),
+				content: std::fs::read_to_string(path)?,
+			});
+		}
+	}
+
+	for dir in TEMPLATE_DIRS {
+		let Ok(entries) = std::fs::read_dir(dir) else {
+			continue;
+		};
+		let mut paths: Vec<_> = entries
+			.filter_map(|e| e.ok().map(|e| e.path()))
+			.filter(|p| {
+				p.extension()
+					.is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+			})
+			.collect();
+		paths.sort();
+		for path in paths {
+			templates.push(Template {
+				path: path.to_string_lossy().into_owned(),
+				content: std::fs::read_to_string(&path)?,
+			});
+		}
+	}
+
+	Ok(templates)
+}
+
+/// Check `body` against the template it conforms to best.
+pub fn check_template(body: &str, templates: &[Template]) -> RuleResult {
+	templates
+		.iter()
+		.map(|t| check_against(body, t))
+		.min_by_key(|violations| violations.len())
+		.unwrap_or_default()
+}
+
+fn check_against(body: &str, template: &Template) -> RuleResult {
+	let mut violations = Vec::new();
+	let body_sections = sections(body);
+
+	for expected in sections(&template.content) {
+		let heading = &expected.heading;
+		if heading.text.is_empty() {
+			continue;
+		}
+		let display = format!("{} {}", "#".repeat(heading.level), heading.text);
+
+		let Some(actual) = body_sections
+			.iter()
+			.find(|s| s.heading.text.eq_ignore_ascii_case(&heading.text))
+		else {
+			violations.push(
+				Violation::error(
+					"template/missing-section",
+					format!(
+						"PR description is missing the '{}' section from the PR template {}",
+						display, template.path
+					),
+				)
+				.with_suggestion(format!("Restore the '{}' section and fill it in", display)),
+			);
+			continue;
+		};
+
+		let content = normalize(&actual.content);
+		let has_text = content.lines().any(|l| parse_heading(l).is_none());
+		if !has_text {
+			violations.push(Violation::error(
+				"template/empty-section",
+				format!(
+					"Section '{}' from the PR template {} is empty",
+					display, template.path
+				),
+			));
+			continue;
+		}
+
+		// An untouched checklist is left to `body.require_checked_boxes`
+		let placeholder = normalize(&expected.content);
+		if content == placeholder && checkboxes(&expected.content).is_empty() {
+			violations.push(
+				Violation::error(
+					"template/placeholder-section",
+					format!(
+						"Section '{}' still contains the placeholder text from the PR template {}",
+						display, template.path
+					),
+				)
+				.with_suggestion("Replace the placeholder with a description of your change"),
+			);
+		}
+	}
+
+	violations
+}
+
+/// Drop comments and blank lines so placeholder text compares reliably.
+fn normalize(content: &str) -> String {
+	strip_comments(content)
+		.lines()
+		.map(str::trim)
+		.filter(|l| !l.is_empty())
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const TEMPLATE: &str = "## Summary\n<!-- What does this change? -->\n\n## Test plan\nDescribe how you tested.\n\n## Checklist\n- [ ] Tests added\n";
+
+	fn template() -> Template {
+		Template {
+			path: ".github/pull_request_template.md".to_string(),
+			content: TEMPLATE.to_string(),
+		}
+	}
+
+	#[test]
+	fn test_conforming_body() {
+		let body = "## Summary\nAdds a flag.\n\n## Test plan\nRan cargo test.\n\n## Checklist\n- [ ] Tests added\n";
+		assert!(check_template(body, &[template()]).is_empty());
+	}
+
+	#[test]
+	fn test_missing_section() {
+		let body = "## Summary\nAdds a flag.\n\n## Checklist\n- [x] Tests added\n";
+		let violations = check_template(body, &[template()]);
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "template/missing-section");
+		// The template is usually not part of the diff, so it is named in
+		// the message instead of being annotated
+		assert!(violations[0].location.is_none());
+		assert!(violations[0].message.ends_with(
+			"'## Test plan' section from the PR template .github/pull_request_template.md"
+		));
+	}
+
+	#[test]
+	fn test_empty_and_placeholder_sections() {
+		let body = "## Summary\n<!-- What does this change? -->\n\n## Test plan\nDescribe how you tested.\n\n## Checklist\n- [x] Tests added\n";
+		let violations = check_template(body, &[template()]);
+		let ids: Vec<&str> = violations.iter().map(|v| v.rule_id.as_str()).collect();
+		assert_eq!(
+			ids,
+			vec!["template/empty-section", "template/placeholder-section"]
+		);
+	}
+
+	#[test]
+	fn test_best_matching_template() {
+		let bug = Template {
+			path: ".github/PULL_REQUEST_TEMPLATE/bug.md".to_string(),
+			content: "## Root cause\n\n## Fix\n".to_string(),
+		};
+		let body = "## Root cause\nOff by one.\n\n## Fix\nUse <=.\n";
+		assert!(check_template(body, &[template(), bug]).is_empty());
+	}
+}
@@ -12,11 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::config::TitleRule;
-use crate::github::PullRequest;
-use crate::rules::{RuleResult, Violation};
+use crate::config::{Config, TitleRule};
+use crate::github::{PrContext, PullRequest};
+use crate::rules::conventional::check_conventional;
+use crate::rules::{ConfiguredRule, Rule, RuleResult, Violation};
+use async_trait::async_trait;
 use regex::Regex;
 
+pub struct TitleCheck {
+	rule: TitleRule,
+}
+
+impl TitleCheck {
+	pub fn new(rule: TitleRule) -> Self {
+		Self { rule }
+	}
+}
+
+impl ConfiguredRule for TitleCheck {
+	const ID: &'static str = "title";
+	type Config = TitleRule;
+
+	fn section(config: &Config) -> Option<&TitleRule> {
+		config.title.as_ref()
+	}
+
+	fn from_config(rule: &TitleRule, _: &Config) -> crate::error::Result<Self> {
+		Ok(Self::new(rule.clone()))
+	}
+}
+
+#[async_trait]
+impl Rule for TitleCheck {
+	fn id(&self) -> &'static str {
+		Self::ID
+	}
+
+	fn description(&self) -> &'static str {
+		"PR title pattern, length and Conventional Commits format"
+	}
+
+	async fn evaluate(&self, ctx: &PrContext<'_>) -> crate::error::Result<RuleResult> {
+		Ok(check_title(&ctx.pr, &self.rule))
+	}
+}
+
 pub fn check_title(pr: &PullRequest, rule: &TitleRule) -> RuleResult {
 	let mut violations = Vec::new();
 
@@ -25,18 +65,23 @@ pub fn check_title(pr: &PullRequest, rule: &TitleRule) -> RuleResult {
 		match Regex::new(pattern) {
 			Ok(re) => {
 				if !re.is_match(&pr.title) {
-					violations.push(Violation {
-						message: format!(
-							"PR title '{}' does not match required pattern: {}",
-							pr.title, pattern
-						),
-					});
+					violations.push(
+						Violation::error(
+							"title/pattern",
+							format!(
+								"PR title '{}' does not match required pattern: {}",
+								pr.title, pattern
+							),
+						)
+						.with_suggestion("Use a title like 'feat: add user authentication'"),
+					);
 				}
 			}
 			Err(e) => {
-				violations.push(Violation {
-					message: format!("Invalid regex pattern '{}': {}", pattern, e),
-				});
+				violations.push(Violation::error(
+					"title/pattern",
+					format!("Invalid regex pattern '{}': {}", pattern, e),
+				));
 			}
 		}
 	}
@@ -45,28 +90,45 @@ pub fn check_title(pr: &PullRequest, rule: &TitleRule) -> RuleResult {
 	if let Some(min_len) = rule.min_length
 		&& pr.title.len() < min_len
 	{
-		violations.push(Violation {
-			message: format!(
+		violations.push(Violation::error(
+			"title/min-length",
+			format!(
 				"PR title '{}' is too short ({} chars), minimum required: {}",
 				pr.title,
 				pr.title.len(),
 				min_len
 			),
-		});
+		));
 	}
 
 	// Check max_length
 	if let Some(max_len) = rule.max_length
 		&& pr.title.len() > max_len
 	{
-		violations.push(Violation {
-			message: format!(
-				"PR title '{}' is too long ({} chars), maximum allowed: {}",
-				pr.title,
-				pr.title.len(),
+		violations.push(
+			Violation::error(
+				"title/max-length",
+				format!(
+					"PR title '{}' is too long ({} chars), maximum allowed: {}",
+					pr.title,
+					pr.title.len(),
+					max_len
+				),
+			)
+			.with_suggestion(format!(
+				"Shorten the title to at most {} characters",
 				max_len
-			),
-		});
+			)),
+		);
+	}
+
+	if rule.conventional.is_enabled() {
+		violations.extend(check_conventional(
+			"title",
+			&pr.title,
+			&rule.conventional,
+			pr,
+		));
 	}
 
 	violations
@@ -81,6 +143,7 @@ mod tests {
 			number: 1,
 			title: title.to_string(),
 			labels: vec![],
+			..Default::default()
 		}
 	}
 
@@ -91,6 +154,7 @@ mod tests {
 			pattern: Some("^(feat|fix|docs):".to_string()),
 			min_length: None,
 			max_length: None,
+			..Default::default()
 		};
 
 		let violations = check_title(&pr, &rule);
@@ -104,10 +168,12 @@ mod tests {
 			pattern: Some("^(feat|fix|docs):".to_string()),
 			min_length: None,
 			max_length: None,
+			..Default::default()
 		};
 
 		let violations = check_title(&pr, &rule);
 		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rule_id, "title/pattern");
 		assert!(violations[0].message.contains("does not match"));
 	}
 
@@ -118,6 +184,7 @@ mod tests {
 			pattern: None,
 			min_length: Some(10),
 			max_length: None,
+			..Default::default()
 		};
 
 		let violations = check_title(&pr, &rule);
@@ -132,10 +199,23 @@ mod tests {
 			pattern: None,
 			min_length: None,
 			max_length: Some(50),
+			..Default::default()
 		};
 
 		let violations = check_title(&pr, &rule);
 		assert_eq!(violations.len(), 1);
 		assert!(violations[0].message.contains("too long"));
 	}
+
+	#[test]
+	fn test_conventional_options() {
+		let pr = create_pr("feat: Add new feature.");
+		let rule: TitleRule =
+			serde_yaml::from_str("subject_case: lower\nno_trailing_period: true\n").unwrap();
+
+		let violations = check_title(&pr, &rule);
+		assert_eq!(violations.len(), 2);
+		assert_eq!(violations[0].rule_id, "title/subject-case");
+		assert_eq!(violations[1].rule_id, "title/trailing-period");
+	}
 }